@@ -0,0 +1,171 @@
+use crate::day::Part;
+use crate::error::{Error, ParseError};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Name of the file recording the known-good answers, next to each day's `input.txt`.
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// The known-good answers of a day, recorded as a small TOML file:
+///
+/// ```toml
+/// part_one = "969024"
+/// part_two = "230057040"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers recorded at `path`, none when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(text.parse()?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part_one = Some(answer),
+            Part::Two => self.part_two = Some(answer),
+        }
+    }
+}
+
+/// Reads the subset of TOML the answers need: `key = "string"` or `key = 123` lines, blank
+/// lines and `#` comments.
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        for line in s.lines() {
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(s, content, "an answer like `part_one = \"42\"`"))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let part = match key {
+                "part_one" => Part::One,
+                "part_two" => Part::Two,
+                _ => return Err(ParseError::new(s, key, "`part_one` or `part_two`")),
+            };
+            if answers.get(part).is_some() {
+                return Err(ParseError::new(s, key, "each part recorded only once"));
+            }
+
+            answers.set(part, parse_value(value).map_err(|e| e.within(s, value))?);
+        }
+
+        Ok(answers)
+    }
+}
+
+/// A quoted string with `\"` and `\\` escapes, or a bare integer, optionally followed by a
+/// comment. Other files written in the same subset of TOML read their values with it too.
+pub fn parse_value(s: &str) -> Result<String, ParseError> {
+    let end_of_line = |rest: &str| {
+        let rest = rest.trim_start();
+        rest.is_empty() || rest.starts_with('#')
+    };
+
+    if let Some(quoted) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' if end_of_line(&quoted[i + 1..]) => return Ok(value),
+                '"' => return Err(ParseError::new(s, &quoted[i + 1..], "the end of the line")),
+                '\\' => match chars.next() {
+                    Some((_, e)) if e == '"' || e == '\\' => value.push(e),
+                    _ => return Err(ParseError::new(s, &quoted[i..], "`\\\"` or `\\\\`")),
+                },
+                c => value.push(c),
+            }
+        }
+        Err(ParseError::new(s, &s[s.len()..], "a closing `\"`"))
+    } else {
+        let number = s.split('#').next().unwrap_or_default().trim_end();
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+            Ok(number.to_string())
+        } else {
+            Err(ParseError::new(s, number, "a quoted answer like `\"42\"`"))
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                let escaped = answer.replace('\\', "\\\\").replace('"', "\\\"");
+                writeln!(f, "{} = \"{}\"", key, escaped)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let text = "# day 1
+part_one = \"969024\" # with a # inside \"#\"
+
+part_two = 230057040 # a bare integer
+";
+        let answers = text.parse::<Answers>().unwrap();
+        assert_eq!(answers.get(Part::One), Some("969024"));
+        assert_eq!(answers.get(Part::Two), Some("230057040"));
+
+        let answers = Answers {
+            part_one: Some("say \"hi\"".to_string()),
+            part_two: None,
+        };
+        assert_eq!(answers.to_string(), "part_one = \"say \\\"hi\\\"\"\n");
+        assert_eq!(answers.to_string().parse(), Ok(answers));
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let e = "part_one = \"1\"\npart_three = \"2\""
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "part_three"));
+
+        let e = "part_one = \"1".parse::<Answers>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 14, ""));
+
+        let e = "part_two = 1x".parse::<Answers>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 12, "1x"));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::solution::Solution;
+use std::fmt;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Times every phase of solving a day over a number of iterations.
+pub type BenchFn = fn(&str, usize) -> Result<Vec<(Phase, Stats)>, String>;
+
+/// A separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part_one",
+            Phase::PartTwo => "part_two",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Phase::ALL
+            .iter()
+            .copied()
+            .find(|p| p.name() == s)
+            .ok_or_else(|| format!("Unknown phase: {:?}", s))
+    }
+}
+
+/// Summary of the durations measured for one phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// None when there is no sample. With an even count, the median is the lower middle sample.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[(samples.len() - 1) / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Times `parse`, `part_one` and `part_two` separately, `iterations` times each.
+///
+/// Every iteration parses the input again, so that the parts can be timed on a fresh input.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Vec<(Phase, Stats)>, String> {
+    let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input)).map_err(|e| e.to_string())?;
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_one(&parsed)?);
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_two(&parsed)?);
+        samples[2].push(start.elapsed());
+    }
+
+    Phase::ALL
+        .iter()
+        .zip(samples)
+        .map(|(&phase, samples)| {
+            Stats::from_samples(samples)
+                .map(|stats| (phase, stats))
+                .ok_or_else(|| "Cannot benchmark without iterations".to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::ParseError;
+
+    #[test]
+    fn from_samples_test() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(5),
+            })
+        );
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+            Ok(input.len())
+        }
+
+        fn part_two(_: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+            Err("boom".to_string())
+        }
+    }
+
+    #[test]
+    fn bench_test() {
+        assert_eq!(bench::<Lines>("a\nb", 3), Err("boom".to_string()));
+        assert!(bench::<Lines>("a\nb", 0).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use crate::day::{Day, Part};
+use crate::input::{Source, STDIN_ARG};
+use crate::num::Arithmetic;
+use crate::output::{self, Format};
+use std::fmt;
+use std::process;
+
+pub const USAGE: &str = "Usage:
+    dayNN [--format human|json|csv] [--arithmetic checked|big] [PATH | -]
+
+Solves both parts of the day and prints the answers as a table (the default), JSON or CSV,
+each with the day, part, answer or error, and elapsed time.
+
+--arithmetic big solves with integers of any size, for the days whose answers can outgrow
+native integers. By default, they fail on overflow instead.
+
+The input is read from PATH, or from the standard input with -. Without either, the day's own
+input.txt is used, looked for in the day's directory then in the current directory.";
+
+/// Options of a day's binary on top of the common ones, and how they change its solving.
+pub trait DayOptions: Default + fmt::Debug + PartialEq {
+    /// Their description, appended to the usage.
+    const USAGE: &'static str;
+
+    /// Takes `arg` if it is one of the options, reading its value with `value`.
+    fn parse_option(
+        &mut self,
+        arg: &str,
+        value: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String>;
+
+    /// Solves `part` of `input` in `arithmetic`, `day` being the day in that arithmetic.
+    fn solve(
+        &self,
+        day: &Day,
+        arithmetic: Arithmetic,
+        part: Part,
+        input: &str,
+    ) -> Result<String, String>;
+
+    /// What to print instead of the answers when the options ask for a report of `input`.
+    fn report(&self, _input: &str, _format: Format) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+/// The options of the days having none of their own.
+#[derive(Debug, Default, PartialEq)]
+pub struct NoOptions;
+
+impl DayOptions for NoOptions {
+    const USAGE: &'static str = "";
+
+    fn parse_option(
+        &mut self,
+        _: &str,
+        _: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    fn solve(&self, day: &Day, _: Arithmetic, part: Part, input: &str) -> Result<String, String> {
+        day.solve(part, input)
+    }
+}
+
+/// What a day's binary was asked to do.
+#[derive(Debug, PartialEq)]
+pub enum Command<O = NoOptions> {
+    Solve {
+        format: Format,
+        /// None for the day's own input.
+        input: Option<Source>,
+        arithmetic: Arithmetic,
+        options: O,
+    },
+    Help,
+}
+
+/// Parses the arguments following the program name.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    parse_args_with(args)
+}
+
+/// Parses the arguments following the program name, the day's own options included.
+pub fn parse_args_with<O, I>(args: I) -> Result<Command<O>, String>
+where
+    O: DayOptions,
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let mut format = Format::Human;
+    let mut input = None;
+    let mut arithmetic = Arithmetic::Checked;
+    let mut options = O::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value after {}", arg))
+        };
+
+        match arg.as_str() {
+            "--format" | "-f" => format = value()?.parse()?,
+            "--arithmetic" | "-a" => arithmetic = value()?.parse()?,
+            "--help" | "-h" => return Ok(Command::Help),
+            other if other.starts_with('-') && other != STDIN_ARG => {
+                if !options.parse_option(other, &mut value)? {
+                    return Err(format!("Unknown option: {:?}", other));
+                }
+            }
+            other => match input {
+                None => input = Some(Source::from_arg(other)),
+                Some(_) => return Err(format!("Unexpected second input: {:?}", other)),
+            },
+        }
+    }
+
+    Ok(Command::Solve {
+        format,
+        input,
+        arithmetic,
+        options,
+    })
+}
+
+/// The whole `main` of a day's binary: solves both parts and prints them in the requested
+/// format, exiting with an error code when something failed.
+pub fn main(day: &Day) {
+    main_with::<NoOptions>(day)
+}
+
+/// The `main` of a day's binary taking options of its own.
+pub fn main_with<O: DayOptions>(day: &Day) {
+    let usage = format!("{}{}", USAGE, O::USAGE);
+    let command = parse_args_with::<O, _>(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, usage);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Help => println!("{}", usage),
+        Command::Solve {
+            format,
+            input,
+            arithmetic,
+            options,
+        } => {
+            let day = day.in_arithmetic(arithmetic).unwrap_or_else(|| {
+                eprintln!("Day {} has no big-integer mode", day.number);
+                process::exit(2);
+            });
+            let input = day.read(input);
+            if let Some(report) = input.as_ref().ok().and_then(|i| options.report(i, format)) {
+                match report {
+                    Ok(report) => print!("{}", report),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        process::exit(1);
+                    }
+                }
+                return;
+            }
+
+            let outcomes = day.run_with(&Part::ALL, &input, |part, input| {
+                options.solve(&day, arithmetic, part, input)
+            });
+            print!("{}", output::format_outcomes(&outcomes, format));
+            eprint!("{}", output::format_errors(&outcomes));
+
+            if outcomes.iter().any(|o| o.answer.is_err()) {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(
+            parse_args(args("")),
+            Ok(Command::Solve {
+                format: Format::Human,
+                input: None,
+                arithmetic: Arithmetic::Checked,
+                options: NoOptions,
+            })
+        );
+        assert_eq!(
+            parse_args(args("--format csv ../day02/input.txt --arithmetic big")),
+            Ok(Command::Solve {
+                format: Format::Csv,
+                input: Some(Source::File("../day02/input.txt".into())),
+                arithmetic: Arithmetic::Big,
+                options: NoOptions,
+            })
+        );
+        assert_eq!(
+            parse_args(args("-")),
+            Ok(Command::Solve {
+                format: Format::Human,
+                input: Some(Source::Stdin),
+                arithmetic: Arithmetic::Checked,
+                options: NoOptions,
+            })
+        );
+        assert_eq!(parse_args(args("-h")), Ok(Command::Help));
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Slope(Option<u64>);
+
+    impl DayOptions for Slope {
+        const USAGE: &'static str = "";
+
+        fn parse_option(
+            &mut self,
+            arg: &str,
+            value: &mut dyn FnMut() -> Result<String, String>,
+        ) -> Result<bool, String> {
+            if arg == "--slope" {
+                self.0 = Some(value()?.parse().map_err(|_| "Invalid slope")?);
+            }
+            Ok(arg == "--slope")
+        }
+
+        fn solve(&self, _: &Day, _: Arithmetic, _: Part, _: &str) -> Result<String, String> {
+            Ok(format!("{:?}", self.0))
+        }
+    }
+
+    #[test]
+    fn parse_args_with_test() {
+        assert_eq!(
+            parse_args_with(args("--slope 3 -f json -")),
+            Ok(Command::Solve {
+                format: Format::Json,
+                input: Some(Source::Stdin),
+                arithmetic: Arithmetic::Checked,
+                options: Slope(Some(3)),
+            })
+        );
+        assert!(parse_args_with::<Slope, _>(args("--slope")).is_err());
+        assert!(parse_args_with::<Slope, _>(args("--slope x")).is_err());
+        assert!(parse_args_with::<Slope, _>(args("--angle 3")).is_err());
+        assert!(parse_args(args("--slope 3")).is_err());
+    }
+
+    #[test]
+    fn parse_args_errors_test() {
+        assert!(parse_args(args("--format")).is_err());
+        assert!(parse_args(args("--format yaml")).is_err());
+        assert!(parse_args(args("--arithmetic float")).is_err());
+        assert!(parse_args(args("--input input.txt")).is_err());
+        assert!(parse_args(args("input.txt -")).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::answers::ANSWERS_FILE_NAME;
+use crate::bench::{self, BenchFn, Phase, Stats};
+use crate::generate::{GenerateFn, Options, Rng};
+use crate::input::Source;
+use crate::num::Arithmetic;
+use crate::output::Outcome;
+use crate::solution::{self, Solution};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Instant;
+
+/// Solves one part of a puzzle from the raw input text.
+pub type PartFn = fn(&str) -> Result<String, String>;
+
+/// Everything the runner needs to know about a day.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    /// Directory holding the day's `Cargo.toml` and `input.txt`.
+    pub manifest_dir: &'static str,
+    /// Puzzle input embedded in the source, for the days without an `input.txt`.
+    pub input: Option<&'static str>,
+    pub part_one: PartFn,
+    pub part_two: PartFn,
+    pub bench: BenchFn,
+    /// Writes random inputs in the day's format, for the days having a generator.
+    pub generate: Option<GenerateFn>,
+    /// Both parts in big-integer arithmetic, for the days whose answers can outgrow native
+    /// integers.
+    pub big: Option<(PartFn, PartFn)>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8, manifest_dir: &'static str) -> Day {
+        Day {
+            number,
+            manifest_dir,
+            input: None,
+            part_one: solution::solve_part_one::<S>,
+            part_two: solution::solve_part_two::<S>,
+            bench: bench::bench::<S>,
+            generate: None,
+            big: None,
+        }
+    }
+
+    /// Embeds the puzzle input instead of reading `input.txt`.
+    pub const fn with_input(self, input: &'static str) -> Day {
+        Day {
+            input: Some(input),
+            ..self
+        }
+    }
+
+    pub const fn with_generator(self, generate: GenerateFn) -> Day {
+        Day {
+            generate: Some(generate),
+            ..self
+        }
+    }
+
+    /// Solves both parts with `S` in big-integer arithmetic.
+    pub const fn with_big<S: Solution>(self) -> Day {
+        Day {
+            big: Some((solution::solve_part_one::<S>, solution::solve_part_two::<S>)),
+            ..self
+        }
+    }
+
+    /// The day solving its parts in `arithmetic`, None when it has no big-integer mode.
+    pub fn in_arithmetic(&self, arithmetic: Arithmetic) -> Option<Day> {
+        match (arithmetic, self.big) {
+            (Arithmetic::Checked, _) => Some(*self),
+            (Arithmetic::Big, Some((part_one, part_two))) => Some(Day {
+                part_one,
+                part_two,
+                ..*self
+            }),
+            (Arithmetic::Big, None) => None,
+        }
+    }
+
+    /// A random input from `seed`, None when the day has no generator.
+    pub fn generate(&self, seed: u64, options: &Options) -> Option<String> {
+        self.generate
+            .map(|generate| generate(&mut Rng::new(seed), options))
+    }
+
+    pub fn source(&self) -> io::Result<Source> {
+        match self.input {
+            Some(input) => Ok(Source::Inline(input.to_string())),
+            None => Source::discover(self.manifest_dir),
+        }
+    }
+
+    /// Where the known-good answers of the day are recorded, next to its `input.txt`.
+    pub fn answers_path(&self) -> PathBuf {
+        Path::new(self.manifest_dir).join(ANSWERS_FILE_NAME)
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<String, String> {
+        match part {
+            Part::One => (self.part_one)(input),
+            Part::Two => (self.part_two)(input),
+        }
+    }
+
+    /// The text of `source`, or of the day's own input when none is given.
+    pub fn read(&self, source: Option<Source>) -> Result<String, String> {
+        source
+            .map_or_else(|| self.source(), Ok)
+            .and_then(|source| source.read())
+            .map_err(|e| format!("Cannot read input: {}", e))
+    }
+
+    /// Solves `parts` from `source`, or from the day's own input when none is given, timing
+    /// each part.
+    pub fn run(&self, parts: &[Part], source: Option<Source>) -> Vec<Outcome> {
+        self.run_with(parts, &self.read(source), |part, input| {
+            self.solve(part, input)
+        })
+    }
+
+    /// Like `run` on the text `input` already read, solving with `solve` rather than the day's
+    /// own parts.
+    pub fn run_with<F>(
+        &self,
+        parts: &[Part],
+        input: &Result<String, String>,
+        solve: F,
+    ) -> Vec<Outcome>
+    where
+        F: Fn(Part, &str) -> Result<String, String>,
+    {
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match input {
+                    Ok(input) => solve(part, input),
+                    Err(e) => Err(e.clone()),
+                };
+
+                Outcome {
+                    day: self.number,
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect()
+    }
+
+    /// Times parsing and both parts separately over `iterations` runs.
+    pub fn bench(&self, input: &str, iterations: usize) -> Result<Vec<(Phase, Stats)>, String> {
+        (self.bench)(input, iterations)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            p => Err(format!("Unknown part: {:?}, expected 1 or 2", p)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::error::{parse_number, ParseError};
+    use crate::num::{try_sum, BigInt, Integer};
+    use std::marker::PhantomData;
+
+    #[test]
+    fn part_from_str_test() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    struct Sum<N = u64>(PhantomData<N>);
+
+    impl<N: Integer> Solution for Sum<N> {
+        type Input<'a> = Vec<N>;
+        type PartOne = N;
+        type PartTwo = N;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input.split(',').map(|n| parse_number(input, n)).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+            Ok(try_sum(input.iter().cloned())?)
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+            let three = N::from_u64(3)?;
+            solution::found(input.iter().find(|&n| *n > three).cloned())
+        }
+    }
+
+    #[test]
+    fn solve_test() {
+        let day = Day::new::<Sum>(0, env!("CARGO_MANIFEST_DIR")).with_input("1,2,3");
+
+        let input = day.source().unwrap().read().unwrap();
+        assert_eq!(day.solve(Part::One, &input), Ok("6".to_string()));
+        assert_eq!(
+            day.solve(Part::Two, &input),
+            Err("No answer found".to_string())
+        );
+        assert!(day.solve(Part::One, "1,x").is_err());
+    }
+
+    #[test]
+    fn run_test() {
+        let day = Day::new::<Sum>(0, env!("CARGO_MANIFEST_DIR")).with_input("1,2,3");
+
+        let outcomes = day.run(&Part::ALL, None);
+        assert_eq!(
+            outcomes
+                .iter()
+                .map(|o| (o.day, o.part, o.answer.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, Part::One, Ok("6".to_string())),
+                (0, Part::Two, Err("No answer found".to_string()))
+            ]
+        );
+
+        let outcomes = day.run(&[Part::One], Some(Source::Inline("4,5".to_string())));
+        assert_eq!(outcomes[0].answer, Ok("9".to_string()));
+    }
+
+    #[test]
+    fn in_arithmetic_test() {
+        let day = Day::new::<Sum<u8>>(0, env!("CARGO_MANIFEST_DIR")).with_input("200,100");
+        assert!(day.solve(Part::One, "200,100").is_err());
+        assert!(day.in_arithmetic(Arithmetic::Big).is_none());
+
+        let day = day.with_big::<Sum<BigInt>>();
+        let checked = day.in_arithmetic(Arithmetic::Checked).unwrap();
+        let big = day.in_arithmetic(Arithmetic::Big).unwrap();
+        assert!(checked.solve(Part::One, "200,100").is_err());
+        assert_eq!(big.solve(Part::One, "200,100"), Ok("300".to_string()));
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// A malformed puzzle input, located precisely enough to print a compiler-style diagnostic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, empty when something is missing.
+    pub text: String,
+    /// What was expected instead, e.g. "an instruction like `acc +1`".
+    pub expected: String,
+    /// The whole line holding the offending text, to underline it.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of `input`.
+    pub fn new(input: &str, text: &str, expected: impl Into<String>) -> ParseError {
+        let (line, column, source_line) = locate(input, offset_of(input, text));
+
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    /// Re-locates an error found while parsing `part`, a slice of `input`, relative to `input`.
+    pub fn within(self, input: &str, part: &str) -> ParseError {
+        let (part_line, part_column, _) = locate(input, offset_of(input, part));
+
+        let line = part_line + self.line - 1;
+        let column = if self.line == 1 {
+            part_column + self.column - 1
+        } else {
+            self.column
+        };
+        let source_line = input.lines().nth(line - 1).unwrap_or_default().to_string();
+
+        ParseError {
+            line,
+            column,
+            source_line,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.text.is_empty() {
+            writeln!(f, "error: expected {}", self.expected)?;
+        } else {
+            writeln!(
+                f,
+                "error: expected {}, found {:?}",
+                self.expected, self.text
+            )?;
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let rest_of_line = self
+            .source_line
+            .chars()
+            .count()
+            .saturating_sub(self.column - 1);
+        let underline = self.text.chars().count().min(rest_of_line).max(1);
+
+        writeln!(
+            f,
+            "{}--> line {}, column {}",
+            gutter, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(underline)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `part` in `input`: exact when `part` is a slice of `input`, the first
+/// occurrence otherwise.
+fn offset_of(input: &str, part: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if start <= part_start && part_start + part.len() <= start + input.len() {
+        part_start - start
+    } else {
+        input.find(part).unwrap_or(0)
+    }
+}
+
+/// 1-based line and column of the byte `offset` in `input`, with the line itself.
+fn locate(input: &str, offset: usize) -> (usize, usize, &str) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    let source_line = input[line_start..].lines().next().unwrap_or_default();
+
+    (line, column, source_line)
+}
+
+/// Parses every line of `input` with `parse_line`, locating errors in the whole input.
+pub fn parse_lines<'a, T, F>(input: &'a str, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .map(|line| parse_line(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn parse_number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(input, text, "a number"))
+}
+
+/// Everything that can make a day's binary fail.
+///
+/// `Debug` shows the diagnostic itself, so that returning it from `main` prints something
+/// readable rather than a struct dump.
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    Other(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "error: {}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Other(e) => write!(f, "error: {}", e),
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{}", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Self {
+        Error::Other(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let input = "acc +1\njmp +x\nnop +0";
+        let e = ParseError::new(input, &input[11..13], "a number");
+        assert_eq!((e.line, e.column), (2, 5));
+        assert_eq!(e.text, "+x");
+        assert_eq!(e.source_line, "jmp +x");
+
+        let e = ParseError::new(input, "nop", "something else");
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+
+    #[test]
+    fn within_test() {
+        let input = "1\n2\nab 3";
+        let line = input.lines().nth(2).unwrap();
+        let e = ParseError::new(line, &line[3..], "a letter").within(input, line);
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(e.source_line, "ab 3");
+    }
+
+    #[test]
+    fn parse_lines_test() {
+        let input = "1\n2\n3x\n4";
+        assert_eq!(
+            parse_lines("1\n2", |l| parse_number::<u8>(l, l)),
+            Ok(vec![1, 2])
+        );
+
+        let e = parse_lines(input, |l| parse_number::<u8>(l, l)).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.text, "3x");
+    }
+
+    #[test]
+    fn display_test() {
+        let input = "acc +1\njmp +x";
+        let e = ParseError::new(input, &input[11..], "a number");
+        assert_eq!(
+            e.to_string(),
+            "error: expected a number, found \"+x\"
+ --> line 2, column 5
+  |
+2 | jmp +x
+  |     ^^"
+        );
+
+        let e = ParseError::new(input, &input[input.len()..], "a number");
+        assert_eq!(
+            e.to_string(),
+            "error: expected a number
+ --> line 2, column 7
+  |
+2 | jmp +x
+  |       ^"
+        );
+    }
+}
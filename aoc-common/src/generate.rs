@@ -0,0 +1,115 @@
+use std::ops::RangeInclusive;
+
+/// Writes a random puzzle input for a day.
+pub type GenerateFn = fn(&mut Rng, &Options) -> String;
+
+/// What to generate, each day deciding what its `size` counts.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Options {
+    /// How big the input is, the day's default when none is given.
+    pub size: Option<usize>,
+    /// Whether the input must have an answer for both parts, as the puzzle guarantees.
+    pub solvable: bool,
+}
+
+impl Options {
+    pub fn size_or(&self, default: usize) -> usize {
+        self.size.unwrap_or(default)
+    }
+}
+
+/// A small seeded pseudo-random generator (SplitMix64): the same seed always gives the same
+/// input, on every platform and with every version of the dependencies since there are none.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` being greater than 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Cannot pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(n) => start + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index in `0..len`, `len` being greater than 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// A string of `len` characters picked from `alphabet`.
+    pub fn string(&mut self, alphabet: &[u8], len: usize) -> String {
+        (0..len).map(|_| *self.choose(alphabet) as char).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn seed_test() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        // Pinned so that a seed keeps producing the same inputs.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn range_test() {
+        let mut rng = Rng::new(2020);
+
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert!(rng.below(2) < 2);
+        }
+        assert_eq!(rng.range(7..=7), 7);
+        rng.range(0..=u64::MAX);
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::error::{parse_lines, ParseError};
+use std::ops::{Index, IndexMut};
+
+/// Steps to the 4 orthogonal neighbours: up, left, right, down.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Steps to the 8 neighbours, diagonals included, row by row.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular 2D map, stored row after row, `x` going right and `y` going down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns from its cells, row after row.
+    pub fn new(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "Cannot make rows of {} cells out of {} cells",
+            width,
+            cells.len()
+        );
+
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses one cell per character, every line being a row of the same length.
+    ///
+    /// `cell` reads a character, `expected` describing the accepted ones in errors.
+    pub fn parse<F>(s: &str, cell: F, expected: &str) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let rows = parse_lines(s, |l| {
+            l.char_indices()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| ParseError::new(l, &l[i..i + c.len_utf8()], expected))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        let width = match rows.first() {
+            Some(row) if !row.is_empty() => row.len(),
+            _ => return Err(ParseError::new(s, s, format!("rows of {}", expected))),
+        };
+
+        if let Some((line, _)) = s.lines().zip(&rows).find(|(_, row)| row.len() != width) {
+            return Err(ParseError::new(
+                s,
+                line,
+                format!("a row of {} cells", width),
+            ));
+        }
+
+        Ok(Grid::new(width, rows.into_iter().flatten().collect()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, None outside of the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    /// The cell at `(x, y)`, the grid repeating itself in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    fn index_of(&self, x: isize, y: isize) -> Option<usize> {
+        if 0 <= x && (x as usize) < self.width && 0 <= y && (y as usize) < self.height {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The positions one step away from `(x, y)` in the given directions, within the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.index_of(x, y).map(|_| (x as usize, y as usize))
+        })
+    }
+
+    /// The up to 4 orthogonal neighbours of `(x, y)`.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DIRECTIONS_4)
+    }
+
+    /// The up to 8 neighbours of `(x, y)`, diagonals included.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DIRECTIONS_8)
+    }
+
+    /// The first position from `(x, y)` stepping by `(dx, dy)` whose cell `stops` the ray,
+    /// None when it leaves the grid first.
+    pub fn cast<F>(
+        &self,
+        x: usize,
+        y: usize,
+        (dx, dy): (isize, isize),
+        stops: F,
+    ) -> Option<(usize, usize)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if (dx, dy) == (0, 0) {
+            return None;
+        }
+
+        let (mut x, mut y) = (x as isize, y as isize);
+        loop {
+            x += dx;
+            y += dy;
+            if stops(self.get(x, y)?) {
+                return Some((x as usize, y as usize));
+            }
+        }
+    }
+
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.cells.iter().filter(|c| predicate(c)).count()
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid::new(self.width, self.cells.iter().map(f).collect())
+    }
+
+    /// Writes one character per cell and one line per row, the way `parse` reads them.
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.rows()
+            .map(|row| row.iter().map(&cell).chain(Some('\n')).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid of the same `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, vec![value; width * height])
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "Column {} is out of the grid", x);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "Column {} is out of the grid", x);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("ab.\n.cd", |c| Some(c).filter(char::is_ascii), "ASCII").unwrap()
+    }
+
+    #[test]
+    fn access_test() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'c');
+        assert_eq!(grid.get(2, 0), Some(&'.'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+        assert_eq!(grid.get_wrapping(4, 3), &'c');
+        assert_eq!(grid.get_wrapping(-1, -2), &'.');
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(2, 1).count(), 3);
+        assert_eq!(grid.cast(0, 1, (1, 0), |&c| c != '.'), Some((1, 1)));
+        assert_eq!(grid.cast(0, 0, (1, 1), |&c| c == 'a'), None);
+        assert_eq!(grid.count(|&c| c == '.'), 2);
+    }
+
+    #[test]
+    fn parse_and_render_test() {
+        let grid = grid();
+        assert_eq!(grid.render(|&c| c), "ab.\n.cd\n");
+        assert_eq!(
+            grid.map(|&c| c == '.')
+                .render(|&b| if b { '#' } else { ' ' }),
+            "  #\n#  \n"
+        );
+
+        let cell = |c| match c {
+            '.' | '#' => Some(c),
+            _ => None,
+        };
+        let e = Grid::parse("..#\n.x.", cell, "`.` or `#`").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+        let e = Grid::parse("..#\n.#", cell, "`.` or `#`").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, ".#"));
+        assert!(Grid::parse("", cell, "`.` or `#`").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Name of the puzzle input file expected next to each day's `Cargo.toml`.
+pub const INPUT_FILE_NAME: &str = "input.txt";
+
+/// The argument standing for the standard input instead of a file.
+pub const STDIN_ARG: &str = "-";
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    /// Looks for `input.txt` in `manifest_dir` first, then in the current directory.
+    ///
+    /// Binaries pass `env!("CARGO_MANIFEST_DIR")` so that each day reads its own input from
+    /// anywhere in the workspace, next to its own `answers.toml`. The current directory only
+    /// serves a day built without an input of its own.
+    pub fn discover(manifest_dir: &str) -> io::Result<Source> {
+        Source::discover_in(Path::new(manifest_dir), Path::new(""))
+    }
+
+    /// Looks for `input.txt` in `manifest_dir` first, then in `fallback_dir`, the current
+    /// directory when empty.
+    fn discover_in(manifest_dir: &Path, fallback_dir: &Path) -> io::Result<Source> {
+        let candidates = [
+            manifest_dir.join(INPUT_FILE_NAME),
+            fallback_dir.join(INPUT_FILE_NAME),
+        ];
+
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .map(|path| Source::File(path.clone()))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("Cannot find puzzle input, tried: {:?}", candidates),
+                )
+            })
+    }
+
+    /// The input named on a command line: `-` for stdin, a path otherwise.
+    pub fn from_arg<P: AsRef<Path>>(arg: P) -> Source {
+        match arg.as_ref() {
+            path if path == Path::new(STDIN_ARG) => Source::Stdin,
+            path => Source::File(path.to_path_buf()),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => read_from(io::stdin().lock()),
+            Source::Inline(s) => Ok(s.clone()),
+        }
+    }
+}
+
+/// Reads everything from `reader` into a `String`.
+pub fn read_from<R: Read>(mut reader: R) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn discover_test() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let source = Source::discover_in(manifest_dir, manifest_dir);
+        assert_eq!(source.map_err(|e| e.kind()), Err(io::ErrorKind::NotFound));
+
+        let day01 = manifest_dir.join("../day01");
+        let source = Source::discover(day01.to_str().unwrap()).unwrap();
+        assert_eq!(source, Source::File(day01.join(INPUT_FILE_NAME)));
+        assert!(!source.read().unwrap().is_empty());
+
+        // From another day's directory, like day 3's map.
+        let elsewhere = std::env::temp_dir().join("aoc-discover-test");
+        fs::create_dir_all(&elsewhere).unwrap();
+        fs::write(elsewhere.join(INPUT_FILE_NAME), "....#...\n").unwrap();
+
+        let day02 = manifest_dir.join("../day02");
+        let found = Source::discover_in(&day02, &elsewhere).unwrap();
+        assert_eq!(found, Source::File(day02.join(INPUT_FILE_NAME)));
+        let fallback = Source::discover_in(manifest_dir, &elsewhere).unwrap();
+        assert_eq!(fallback.read().unwrap(), "....#...\n");
+    }
+
+    #[test]
+    fn read_test() {
+        let source = Source::Inline("13,0,10".to_string());
+        assert_eq!(source.read().unwrap(), "13,0,10");
+
+        assert_eq!(read_from("1\n2\n".as_bytes()).unwrap(), "1\n2\n");
+
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("../day01/input.txt"),
+            Source::File(PathBuf::from("../day01/input.txt"))
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Code shared by every day of the Advent of Code 2020: locating and reading the puzzle input,
+//! the `Solution` trait every day implements, reporting malformed inputs, recording known-good
+//! answers, timing the solvers, generating random inputs, describing a day to the runner,
+//! printing the answers, a grid for the 2D maps, parser combinators for the puzzle inputs, memoized
+//! recursion, checked and big-integer arithmetic, and the command line every day's binary shares.
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod day;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memo;
+pub mod num;
+pub mod output;
+pub mod parser;
+pub mod solution;
@@ -0,0 +1,126 @@
+//! Memoized recursion, for the puzzles whose naive recursion explores the same states again
+//! and again.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How well a `Memo` did: lookups answered from the cache, and values actually computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// The values computed so far, by state.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// The value of `key`, computed once by `f`, which gets the memo back to recurse with.
+    pub fn get<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = f(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The value of `key`, `f` recursing on the states it depends on through its first
+    /// argument rather than by calling itself.
+    pub fn solve<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, &K) -> V,
+    {
+        self.get(key, |memo, key| f(&mut |k| memo.solve(k, f), key))
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// How many states have a value.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get(n, |memo, &n| match n {
+            0 | 1 => n,
+            n => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+        })
+    }
+
+    #[test]
+    fn get_test() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 91);
+        assert_eq!(
+            memo.stats(),
+            CacheStats {
+                hits: 88,
+                misses: 91
+            }
+        );
+
+        assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+        assert_eq!(
+            memo.stats(),
+            CacheStats {
+                hits: 89,
+                misses: 91
+            }
+        );
+    }
+
+    #[test]
+    fn solve_test() {
+        // Lattice paths from (x, y) to (0, 0), stepping left or up.
+        let paths = |recurse: &mut dyn FnMut((u64, u64)) -> u64, &(x, y): &(u64, u64)| {
+            if x == 0 || y == 0 {
+                1
+            } else {
+                recurse((x - 1, y)) + recurse((x, y - 1))
+            }
+        };
+
+        let mut memo = Memo::new();
+        assert_eq!(memo.solve((16, 16), &paths), 601_080_390);
+        assert_eq!(memo.stats().misses, memo.len());
+        assert!(memo.len() < 17 * 17);
+    }
+}
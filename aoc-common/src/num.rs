@@ -0,0 +1,413 @@
+//! Integer arithmetic that never silently wraps: native integers checked for overflow, or
+//! `BigInt` when the answers outgrow them.
+//!
+//! Solvers written against `Integer` run in either mode, the days choosing the native type of
+//! their checked mode.
+
+use std::cmp::Ordering;
+use std::convert::TryInto;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// An arithmetic operation whose result does not fit, or a division by zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Arithmetic overflow, try the big-integer mode")
+    }
+}
+
+impl From<Overflow> for String {
+    fn from(overflow: Overflow) -> String {
+        overflow.to_string()
+    }
+}
+
+/// How a day computes its answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Native integers, failing on overflow.
+    Checked,
+    /// Integers of any size, for inputs whose answers do not fit in native ones.
+    Big,
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checked" => Ok(Arithmetic::Checked),
+            "big" => Ok(Arithmetic::Big),
+            a => Err(format!(
+                "Unknown arithmetic: {:?}, expected checked or big",
+                a
+            )),
+        }
+    }
+}
+
+/// An integer whose every operation reports overflows rather than wrapping.
+pub trait Integer: Clone + Eq + Ord + Hash + fmt::Debug + fmt::Display + FromStr {
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn from_u64(n: u64) -> Result<Self, Overflow>;
+
+    fn from_i64(n: i64) -> Result<Self, Overflow>;
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, Overflow>;
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, Overflow>;
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, Overflow>;
+
+    /// The remainder of the division by `rhs`, of the sign of `self` like `%`.
+    fn try_rem(&self, rhs: &Self) -> Result<Self, Overflow>;
+
+    fn try_abs(&self) -> Result<Self, Overflow> {
+        if *self < Self::zero() {
+            Self::zero().try_sub(self)
+        } else {
+            Ok(self.clone())
+        }
+    }
+
+    fn try_neg(&self) -> Result<Self, Overflow> {
+        Self::zero().try_sub(self)
+    }
+}
+
+macro_rules! native_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_u64(n: u64) -> Result<Self, Overflow> {
+                    n.try_into().map_err(|_| Overflow)
+                }
+
+                fn from_i64(n: i64) -> Result<Self, Overflow> {
+                    n.try_into().map_err(|_| Overflow)
+                }
+
+                fn try_add(&self, rhs: &Self) -> Result<Self, Overflow> {
+                    self.checked_add(*rhs).ok_or(Overflow)
+                }
+
+                fn try_sub(&self, rhs: &Self) -> Result<Self, Overflow> {
+                    self.checked_sub(*rhs).ok_or(Overflow)
+                }
+
+                fn try_mul(&self, rhs: &Self) -> Result<Self, Overflow> {
+                    self.checked_mul(*rhs).ok_or(Overflow)
+                }
+
+                fn try_rem(&self, rhs: &Self) -> Result<Self, Overflow> {
+                    self.checked_rem(*rhs).ok_or(Overflow)
+                }
+            }
+        )*
+    };
+}
+
+native_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Sums `values`, failing on the first overflow.
+pub fn try_sum<N: Integer, I: IntoIterator<Item = N>>(values: I) -> Result<N, Overflow> {
+    values
+        .into_iter()
+        .try_fold(N::zero(), |sum, n| sum.try_add(&n))
+}
+
+/// Multiplies `values`, failing on the first overflow.
+pub fn try_product<N: Integer, I: IntoIterator<Item = N>>(values: I) -> Result<N, Overflow> {
+    values
+        .into_iter()
+        .try_fold(N::one(), |product, n| product.try_mul(&n))
+}
+
+/// A signed integer of any size.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// Base 2^32 digits, least significant first, without leading zeros: zero has none.
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let s = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(s as u32);
+        carry = s >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+/// `a - b`, `a` being at least `b`.
+fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &digit) in a.iter().enumerate() {
+        let d = digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        difference.push(d.rem_euclid(1 << 32) as u32);
+        borrow = (d < 0) as i64;
+    }
+    difference
+}
+
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let p = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = p as u32;
+            carry = p >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+/// The quotient and remainder of `a / b`, bit by bit, `b` not being zero.
+fn div_rem(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = Vec::new();
+    for i in (0..a.len() * 32).rev() {
+        remainder = add(&remainder, &remainder);
+        remainder[0] |= (a[i / 32] >> (i % 32)) & 1;
+        while remainder.last() == Some(&0) {
+            remainder.pop();
+        }
+        if compare(&remainder, b) != Ordering::Less {
+            remainder = sub(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+/// The quotient and remainder of `a / d`, for a single digit divisor.
+fn div_rem_digit(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for i in (0..a.len()).rev() {
+        let n = (remainder << 32) | a[i] as u64;
+        quotient[i] = (n / d as u64) as u32;
+        remainder = n % d as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+impl From<u64> for BigInt {
+    fn from(n: u64) -> BigInt {
+        BigInt::new(false, vec![n as u32, (n >> 32) as u32])
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> BigInt {
+        let BigInt { magnitude, .. } = BigInt::from(n.unsigned_abs());
+        BigInt::new(n < 0, magnitude)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare(&self.magnitude, &other.magnitude),
+            (true, true) => compare(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1u64)
+    }
+
+    fn from_u64(n: u64) -> Result<Self, Overflow> {
+        Ok(BigInt::from(n))
+    }
+
+    fn from_i64(n: i64) -> Result<Self, Overflow> {
+        Ok(BigInt::from(n))
+    }
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, Overflow> {
+        let (a, b) = (&self.magnitude, &rhs.magnitude);
+        Ok(if self.negative == rhs.negative {
+            BigInt::new(self.negative, add(a, b))
+        } else if compare(a, b) == Ordering::Less {
+            BigInt::new(rhs.negative, sub(b, a))
+        } else {
+            BigInt::new(self.negative, sub(a, b))
+        })
+    }
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, Overflow> {
+        self.try_add(&BigInt::new(!rhs.negative, rhs.magnitude.clone()))
+    }
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, Overflow> {
+        Ok(BigInt::new(
+            self.negative != rhs.negative,
+            mul(&self.magnitude, &rhs.magnitude),
+        ))
+    }
+
+    fn try_rem(&self, rhs: &Self) -> Result<Self, Overflow> {
+        match rhs.magnitude.as_slice() {
+            [] => Err(Overflow),
+            &[d] => {
+                let (_, r) = div_rem_digit(&self.magnitude, d);
+                Ok(BigInt::new(self.negative, vec![r]))
+            }
+            b => Ok(BigInt::new(self.negative, div_rem(&self.magnitude, b).1)),
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Nine decimal digits at a time, least significant first.
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, chunk) = div_rem_digit(&magnitude, 1_000_000_000);
+            chunks.push(chunk);
+            magnitude = BigInt::new(false, quotient).magnitude;
+        }
+
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits += &format!("{:09}", chunk);
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("Invalid integer: {:?}", s));
+        }
+
+        let ten = [10];
+        let magnitude = digits
+            .bytes()
+            .fold(Vec::new(), |n, b| add(&mul(&n, &ten), &[(b - b'0') as u32]));
+        Ok(BigInt::new(negative, magnitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn checked_test() {
+        assert_eq!(2i32.try_add(&3), Ok(5));
+        assert_eq!(i32::MAX.try_add(&1), Err(Overflow));
+        assert_eq!(u64::MAX.try_mul(&2), Err(Overflow));
+        assert_eq!(0u64.try_sub(&1), Err(Overflow));
+        assert_eq!(7i64.try_rem(&0), Err(Overflow));
+        assert_eq!(i64::MIN.try_abs(), Err(Overflow));
+        assert_eq!(<i32 as Integer>::from_u64(1 << 40), Err(Overflow));
+        assert_eq!(try_product(vec![1000u32; 4]), Err(Overflow));
+        assert_eq!(try_sum(vec![1u32, 2, 3]), Ok(6));
+    }
+
+    #[test]
+    fn big_test() {
+        let a = big("123456789012345678901234567890");
+        let b = big("-987654321098765432109876543210");
+
+        assert_eq!(
+            a.try_mul(&b).unwrap().to_string(),
+            "-121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!(
+            a.try_add(&b).unwrap().to_string(),
+            "-864197532086419753208641975320"
+        );
+        assert_eq!(
+            a.try_sub(&b).unwrap().to_string(),
+            "1111111110111111111011111111100"
+        );
+        assert_eq!(b.try_rem(&a).unwrap(), big("-9000000000900000000090"));
+        assert_eq!(a.try_rem(&big("97")).unwrap(), big("52"));
+        assert_eq!(a.try_add(&a.try_neg().unwrap()), Ok(BigInt::zero()));
+        assert!(b < a && b.try_abs().unwrap() > a);
+        assert_eq!(BigInt::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(BigInt::from(i64::MIN).to_string(), i64::MIN.to_string());
+        assert_eq!(format!("{:>5}", BigInt::one()), "    1");
+        assert!("12a".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn arithmetic_from_str_test() {
+        assert_eq!("checked".parse(), Ok(Arithmetic::Checked));
+        assert_eq!("big".parse(), Ok(Arithmetic::Big));
+        assert!("float".parse::<Arithmetic>().is_err());
+    }
+}
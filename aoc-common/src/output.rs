@@ -0,0 +1,252 @@
+use crate::day::Part;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The result of solving one part of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// How answers are printed: a table for people, JSON or CSV for scripts and dashboards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Human,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            f => Err(format!(
+                "Unknown format: {:?}, expected human, json or csv",
+                f
+            )),
+        }
+    }
+}
+
+pub fn format_outcomes(outcomes: &[Outcome], format: Format) -> String {
+    match format {
+        Format::Human => format_summary(outcomes),
+        Format::Json => format_json(outcomes),
+        Format::Csv => format_csv(outcomes),
+    }
+}
+
+/// A table of the answers with their timings, followed by the total time.
+pub fn format_summary(outcomes: &[Outcome]) -> String {
+    let answers = outcomes
+        .iter()
+        .map(|o| match &o.answer {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", summarize_error(e)),
+        })
+        .collect::<Vec<_>>();
+    let width = answers
+        .iter()
+        .map(|a| a.chars().count())
+        .chain(std::iter::once("answer".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut summary = format!("day  part  {:<width$}  time\n", "answer", width = width);
+    for (o, answer) in outcomes.iter().zip(&answers) {
+        summary += &format!(
+            "{:>3}  {:>4}  {:<width$}  {:?}\n",
+            o.day,
+            o.part.number(),
+            answer,
+            o.elapsed,
+            width = width
+        );
+    }
+
+    let total = outcomes.iter().map(|o| o.elapsed).sum::<Duration>();
+    summary += &format!("total: {:?}\n", total);
+    summary
+}
+
+/// One JSON object per outcome, with either an `answer` or an `error`, and the elapsed time in
+/// nanoseconds.
+pub fn format_json(outcomes: &[Outcome]) -> String {
+    let objects = outcomes
+        .iter()
+        .map(|o| {
+            let (answer, error) = match &o.answer {
+                Ok(answer) => (json_string(answer), "null".to_string()),
+                Err(e) => ("null".to_string(), json_string(e)),
+            };
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"error\": {}, \"elapsed_ns\": {}}}",
+                o.day,
+                o.part.number(),
+                answer,
+                error,
+                o.elapsed.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    json_array(&objects)
+}
+
+/// A header then one row per outcome, the answer or the error being left empty.
+pub fn format_csv(outcomes: &[Outcome]) -> String {
+    let mut csv = String::from("day,part,answer,error,elapsed_ns\n");
+    for o in outcomes {
+        let (answer, error) = match &o.answer {
+            Ok(answer) => (answer.as_str(), ""),
+            Err(e) => ("", e.as_str()),
+        };
+        csv += &format!(
+            "{},{},{},{},{}\n",
+            o.day,
+            o.part.number(),
+            csv_field(answer),
+            csv_field(error),
+            o.elapsed.as_nanos()
+        );
+    }
+    csv
+}
+
+/// A JSON array of `objects`, already formatted and indented, one per line.
+pub fn json_array(objects: &[String]) -> String {
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+/// `s` as a quoted JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Quotes a field holding a separator, a quote or a line break.
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// The first line of a possibly multi-line error, which is enough to fit in the summary table.
+pub fn summarize_error(error: &str) -> &str {
+    let first_line = error.lines().next().unwrap_or_default();
+    first_line.strip_prefix("error: ").unwrap_or(first_line)
+}
+
+/// The full errors of the failed outcomes, once per day when both parts failed alike.
+pub fn format_errors(outcomes: &[Outcome]) -> String {
+    let mut errors = String::new();
+    let mut previous: Option<(u8, &str)> = None;
+
+    for o in outcomes {
+        if let Err(e) = &o.answer {
+            if previous != Some((o.day, e.as_str())) {
+                errors += &format!("day {} part {}:\n{}\n", o.day, o.part.number(), e);
+            }
+            previous = Some((o.day, e.as_str()));
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                day: 1,
+                part: Part::One,
+                answer: Ok("969024".to_string()),
+                elapsed: Duration::from_millis(2),
+            },
+            Outcome {
+                day: 12,
+                part: Part::Two,
+                answer: Err("boom, \"bang\"".to_string()),
+                elapsed: Duration::from_millis(3),
+            },
+        ]
+    }
+
+    #[test]
+    fn format_summary_test() {
+        assert_eq!(
+            format_outcomes(&outcomes(), Format::Human),
+            "day  part  answer               time
+  1     1  969024               2ms
+ 12     2  error: boom, \"bang\"  3ms
+total: 5ms
+"
+        );
+    }
+
+    #[test]
+    fn format_structured_test() {
+        assert_eq!(
+            format_outcomes(&outcomes(), Format::Json),
+            r#"[
+  {"day": 1, "part": 1, "answer": "969024", "error": null, "elapsed_ns": 2000000},
+  {"day": 12, "part": 2, "answer": null, "error": "boom, \"bang\"", "elapsed_ns": 3000000}
+]
+"#
+        );
+        assert_eq!(format_outcomes(&[], Format::Json), "[]\n");
+        assert_eq!(json_array(&["  1".to_string()]), "[\n  1\n]\n");
+        assert_eq!(
+            format_outcomes(&outcomes(), Format::Csv),
+            r#"day,part,answer,error,elapsed_ns
+1,1,969024,,2000000
+12,2,,"boom, ""bang""",3000000
+"#
+        );
+    }
+
+    #[test]
+    fn format_errors_test() {
+        let error = "error: expected a number, found \"x\"\n --> line 1, column 1".to_string();
+        let mut outcomes = outcomes();
+        outcomes[0].answer = Err(error.clone());
+        outcomes[1].day = 1;
+        outcomes[1].answer = Err(error.clone());
+
+        assert!(format_summary(&outcomes)
+            .contains("\n  1     1  error: expected a number, found \"x\"  2ms\n"));
+        assert_eq!(
+            format_errors(&outcomes),
+            format!("day 1 part 1:\n{}\n", error)
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! A few parser combinators, enough for the puzzle inputs.
+//!
+//! A parser is any `Fn(&str) -> Parsed<T>` returning what it read with the rest of the input.
+//! Every slice it hands back, and the offending text of a failure, is a slice of its input, so
+//! that `parse_all` can locate errors in the original text.
+
+use crate::error::ParseError;
+use std::str::FromStr;
+
+/// Where and why a parser failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<'a> {
+    /// The offending text, a slice of the input, empty when something is missing.
+    pub at: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            at,
+            expected: expected.into(),
+        }
+    }
+}
+
+/// What a parser read with the rest of the input, or why it failed.
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// The word starting `input` after any leading whitespace, as the offending text of failures.
+pub fn word(input: &str) -> &str {
+    let input = input.trim_start();
+    let end = input.find(char::is_whitespace).unwrap_or(input.len());
+    &input[..end]
+}
+
+/// Runs `parser` on the whole `input`, failing when something is left.
+pub fn parse_all<'a, T, P>(input: &'a str, parser: P) -> Result<T, ParseError>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+{
+    match parser(input) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(ParseError::new(input, word(rest), "the end of the line")),
+        Err(failure) => Err(ParseError::new(input, failure.at, failure.expected)),
+    }
+}
+
+/// Parses every block of `input`, blocks being separated by a blank line, locating errors in the
+/// whole input.
+pub fn parse_blocks<'a, T, F>(input: &'a str, parse_block: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .split("\n\n")
+        .map(|block| parse_block(block).map_err(|e| e.within(input, block)))
+        .collect()
+}
+
+/// Exactly `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(&'a str) -> Parsed<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => Err(Failure::new(word(input), format!("`{}`", expected))),
+    }
+}
+
+/// One or more characters matching `predicate`.
+pub fn take_while1<'a, F>(
+    predicate: F,
+    expected: &'static str,
+) -> impl Fn(&'a str) -> Parsed<'a, &'a str>
+where
+    F: Fn(char) -> bool,
+{
+    move |input: &'a str| {
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+        if end == 0 {
+            Err(Failure::new(word(input), expected))
+        } else {
+            Ok(input.split_at(end))
+        }
+    }
+}
+
+/// One or more spaces or tabs, never a line break.
+pub fn spaces1<'a>() -> impl Fn(&'a str) -> Parsed<'a, &'a str> {
+    take_while1(|c| c == ' ' || c == '\t', "a space")
+}
+
+/// An integer with an optional sign, like `42` or `+3`.
+///
+/// Letters right after the digits are part of the offending text, so that `1x` is reported as
+/// a whole rather than as a `1` followed by something unexpected.
+pub fn integer<'a, T: FromStr>() -> impl Fn(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let sign = input.starts_with(['+', '-']) as usize;
+        let end = input[sign..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(input.len(), |i| sign + i);
+        let (number, rest) = input.split_at(end);
+
+        match number.parse() {
+            Ok(n) => Ok((n, rest)),
+            Err(_) if number.is_empty() => Err(Failure::new(word(input), "a number")),
+            Err(_) => Err(Failure::new(number, "a number")),
+        }
+    }
+}
+
+pub fn map<'a, T, U, P, F>(parser: P, f: F) -> impl Fn(&'a str) -> Parsed<'a, U>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+    F: Fn(T) -> U,
+{
+    move |input| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Checks or converts what `parser` read, `f` failing with its own located failure.
+pub fn and_then<'a, T, U, P, F>(parser: P, f: F) -> impl Fn(&'a str) -> Parsed<'a, U>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+    F: Fn(T) -> Result<U, Failure<'a>>,
+{
+    move |input| {
+        let (value, rest) = parser(input)?;
+        Ok((f(value)?, rest))
+    }
+}
+
+/// The slice of the input `parser` read, rather than its value.
+pub fn recognize<'a, T, P>(parser: P) -> impl Fn(&'a str) -> Parsed<'a, &'a str>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+{
+    move |input: &'a str| {
+        let (_, rest) = parser(input)?;
+        Ok((&input[..input.len() - rest.len()], rest))
+    }
+}
+
+pub fn pair<'a, A, B, P, Q>(first: P, second: Q) -> impl Fn(&'a str) -> Parsed<'a, (A, B)>
+where
+    P: Fn(&'a str) -> Parsed<'a, A>,
+    Q: Fn(&'a str) -> Parsed<'a, B>,
+{
+    move |input| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `parser` after `prefix`, keeping only what `parser` read.
+pub fn preceded<'a, A, T, P, Q>(prefix: P, parser: Q) -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    P: Fn(&'a str) -> Parsed<'a, A>,
+    Q: Fn(&'a str) -> Parsed<'a, T>,
+{
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// `parser` before `suffix`, keeping only what `parser` read.
+pub fn terminated<'a, T, B, P, Q>(parser: P, suffix: Q) -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+    Q: Fn(&'a str) -> Parsed<'a, B>,
+{
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// Whether `failure` happened after reading some of `input`, rather than right at its start.
+fn read_some(failure: &Failure, input: &str) -> bool {
+    failure.at.as_ptr() != word(input).as_ptr()
+}
+
+/// `first`, or `second` when `first` failed without reading anything.
+///
+/// Once `first` read something, its failure is the one that matters.
+pub fn or<'a, T, P, Q>(first: P, second: Q) -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+    Q: Fn(&'a str) -> Parsed<'a, T>,
+{
+    move |input| match first(input) {
+        Err(f) if !read_some(&f, input) => second(input).map_err(|g| {
+            if read_some(&g, input) {
+                g
+            } else {
+                Failure::new(g.at, format!("{} or {}", f.expected, g.expected))
+            }
+        }),
+        result => result,
+    }
+}
+
+/// `parser`, or None when it failed without reading anything.
+pub fn optional<'a, T, P>(parser: P) -> impl Fn(&'a str) -> Parsed<'a, Option<T>>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+{
+    move |input| match parser(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(f) if !read_some(&f, input) => Ok((None, input)),
+        Err(f) => Err(f),
+    }
+}
+
+/// One or more `parser` in a row, until it fails without reading anything.
+pub fn many1<'a, T, P>(parser: P) -> impl Fn(&'a str) -> Parsed<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+{
+    move |input| {
+        let (first, mut rest) = parser(input)?;
+        let mut values = vec![first];
+
+        loop {
+            match parser(rest) {
+                Ok((value, after_value)) => {
+                    values.push(value);
+                    rest = after_value;
+                }
+                Err(f) if !read_some(&f, rest) => return Ok((values, rest)),
+                Err(f) => return Err(f),
+            }
+        }
+    }
+}
+
+/// One or more `parser` separated by `separator`.
+pub fn separated<'a, T, S, P, Q>(parser: P, separator: Q) -> impl Fn(&'a str) -> Parsed<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+    Q: Fn(&'a str) -> Parsed<'a, S>,
+{
+    move |input| {
+        let (first, mut rest) = parser(input)?;
+        let mut values = vec![first];
+
+        while let Ok((_, after_separator)) = separator(rest) {
+            let (value, after_value) = parser(after_separator)?;
+            values.push(value);
+            rest = after_value;
+        }
+
+        Ok((values, rest))
+    }
+}
+
+/// Reports any failure of `parser` as `expected` at the start of its input.
+///
+/// For items better described as a whole, like `a rule like ...`, than by the detail that
+/// went wrong inside them.
+pub fn label<'a, T, P>(parser: P, expected: &'static str) -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    P: Fn(&'a str) -> Parsed<'a, T>,
+{
+    move |input| parser(input).map_err(|_| Failure::new(word(input), expected))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn primitives_test() {
+        assert_eq!(tag("mem")("mem[8]"), Ok(("mem", "[8]")));
+        assert_eq!(tag("mem")("mask = X"), Err(Failure::new("mask", "`mem`")));
+        assert_eq!(integer::<i64>()("+3 x"), Ok((3, " x")));
+        assert_eq!(integer::<u64>()("12,3"), Ok((12, ",3")));
+        assert_eq!(
+            integer::<u64>()("1x = 2"),
+            Err(Failure::new("1x", "a number"))
+        );
+        assert_eq!(integer::<u64>()(""), Err(Failure::new("", "a number")));
+        assert_eq!(
+            recognize(pair(take_while1(char::is_alphabetic, "a word"), tag("!")))("hi! there"),
+            Ok(("hi!", " there"))
+        );
+        assert_eq!(optional(tag("s"))("bag"), Ok((None, "bag")));
+    }
+
+    #[test]
+    fn combinators_test() {
+        let numbers = separated(integer::<u64>(), tag(","));
+        assert_eq!(numbers("7,1,14"), Ok((vec![7, 1, 14], "")));
+        assert_eq!(numbers("7,x"), Err(Failure::new("x", "a number")));
+        let numbers = many1(terminated(integer::<u64>(), optional(spaces1())));
+        assert_eq!(numbers("4 1 5 | 2"), Ok((vec![4, 1, 5], "| 2")));
+
+        let instruction = or(tag("acc"), or(tag("jmp"), tag("nop")));
+        assert_eq!(instruction("nop +0"), Ok(("nop", " +0")));
+        assert_eq!(
+            instruction("jpm +4"),
+            Err(Failure::new("jpm", "`acc` or `jmp` or `nop`"))
+        );
+
+        let address = preceded(tag("mem["), terminated(integer::<u64>(), tag("]")));
+        assert_eq!(address("mem[8] = 1"), Ok((8, " = 1")));
+        assert_eq!(
+            label(&address, "an address like `mem[8]`")("mem(8) = 1"),
+            Err(Failure::new("mem(8)", "an address like `mem[8]`"))
+        );
+    }
+
+    #[test]
+    fn parse_all_test() {
+        let input = "1-3 a";
+        let range = pair(integer::<u64>(), preceded(tag("-"), integer::<u64>()));
+        assert_eq!(parse_all("1-3", &range), Ok((1, 3)));
+
+        let e = parse_all(input, &range).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "a"));
+
+        let e = parse_blocks("1-3\n\n2-x", |b| parse_all(b, &range)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 3, "x"));
+    }
+}
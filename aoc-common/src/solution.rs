@@ -0,0 +1,76 @@
+use crate::error::ParseError;
+use std::fmt::Display;
+
+/// A day of the Advent of Code: how to parse its input and how to solve both parts.
+///
+/// The parsed input may borrow from the text it was parsed from, which spares the days working
+/// on lines or words from copying them.
+pub trait Solution {
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String>;
+}
+
+/// Turns a solver result that may have found nothing into an answer.
+pub fn found<T>(answer: Option<T>) -> Result<T, String> {
+    answer.ok_or_else(|| "No answer found".to_string())
+}
+
+/// Parses `input` then solves part one, formatting the answer.
+pub fn solve_part_one<S: Solution>(input: &str) -> Result<String, String> {
+    let input = S::parse(input).map_err(|e| e.to_string())?;
+    S::part_one(&input).map(|answer| answer.to_string())
+}
+
+/// Parses `input` then solves part two, formatting the answer.
+pub fn solve_part_two<S: Solution>(input: &str) -> Result<String, String> {
+    let input = S::parse(input).map_err(|e| e.to_string())?;
+    S::part_two(&input).map(|answer| answer.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.split_whitespace().collect())
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+            found(
+                input
+                    .iter()
+                    .find(|w| w.starts_with('z'))
+                    .map(|w| w.to_string()),
+            )
+        }
+    }
+
+    #[test]
+    fn solve_test() {
+        assert_eq!(solve_part_one::<Words>("a b c"), Ok("3".to_string()));
+        assert_eq!(solve_part_two::<Words>("a zb c"), Ok("zb".to_string()));
+        assert_eq!(
+            solve_part_two::<Words>("a b c"),
+            Err("No answer found".to_string())
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use crate::cli::BatchOptions;
+use crate::days;
+use aoc_common::answers::ANSWERS_FILE_NAME;
+use aoc_common::day::{Day, Part};
+use aoc_common::input::Source;
+use aoc_common::output::{csv_field, json_array, json_string, summarize_error, Format, Outcome};
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcomes of solving one input file of the batch.
+#[derive(Debug)]
+pub struct Solved {
+    pub path: PathBuf,
+    pub outcomes: Vec<Outcome>,
+}
+
+/// Solves every input file of the directory, on as many threads as asked.
+pub fn batch(options: &BatchOptions) -> Result<Vec<Solved>, String> {
+    let day = days::get(options.day).ok_or_else(|| format!("No day {}", options.day))?;
+    let day = day.in_arithmetic(options.arithmetic).unwrap_or(*day);
+    let paths = inputs(&options.dir)
+        .map_err(|e| format!("Cannot read {}: {}", options.dir.display(), e))?;
+    let jobs = options
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+    Ok(solve_all(&day, &options.parts, paths, jobs))
+}
+
+/// The files of `dir` sorted by name, leaving out hidden files and the recorded answers.
+pub fn inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if entry.file_type()?.is_file() && !name.starts_with('.') && name != ANSWERS_FILE_NAME {
+            paths.push(entry.path());
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// Solves `paths` on `jobs` threads, each taking the next file nobody took yet, keeping the
+/// order of `paths`.
+fn solve_all(day: &Day, parts: &[Part], paths: Vec<PathBuf>, jobs: usize) -> Vec<Solved> {
+    let next = AtomicUsize::new(0);
+    let solved = Mutex::new(Vec::with_capacity(paths.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, paths.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(path) = paths.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcomes = solve(day, parts, path);
+                    solved.lock().unwrap().push(Solved {
+                        path: path.clone(),
+                        outcomes,
+                    });
+                }
+            });
+        }
+    });
+
+    let mut solved = solved.into_inner().unwrap();
+    solved.sort_by(|a, b| a.path.cmp(&b.path));
+    solved
+}
+
+/// Solves one file, a solver panicking on it failing its parts rather than the whole batch.
+fn solve(day: &Day, parts: &[Part], path: &Path) -> Vec<Outcome> {
+    let start = Instant::now();
+
+    panic::catch_unwind(|| day.run(parts, Some(Source::File(path.to_path_buf())))).unwrap_or_else(
+        |payload| {
+            let answer = Err(format!("Panicked: {}", panic_message(&*payload)));
+            parts
+                .iter()
+                .map(|&part| Outcome {
+                    day: day.number,
+                    part,
+                    answer: answer.clone(),
+                    elapsed: start.elapsed(),
+                })
+                .collect()
+        },
+    )
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+pub fn format_batch(solved: &[Solved], format: Format) -> String {
+    match format {
+        Format::Human => format_summary(solved),
+        Format::Json => format_json(solved),
+        Format::Csv => format_csv(solved),
+    }
+}
+
+/// Every file and part with its answer or error and timing, one row each, then how many files
+/// failed.
+fn format_summary(solved: &[Solved]) -> String {
+    let rows = rows(solved)
+        .map(|(path, o)| {
+            let answer = match &o.answer {
+                Ok(answer) => answer.clone(),
+                Err(e) => format!("error: {}", summarize_error(e)),
+            };
+            (path.display().to_string(), o, answer)
+        })
+        .collect::<Vec<_>>();
+    let width = |column: &dyn Fn(&(String, &Outcome, String)) -> usize, header: &str| {
+        rows.iter()
+            .map(column)
+            .chain(Some(header.len()))
+            .max()
+            .unwrap_or(0)
+    };
+    let file_width = width(&|(file, _, _)| file.chars().count(), "file");
+    let answer_width = width(&|(_, _, answer)| answer.chars().count(), "answer");
+
+    let mut summary = format!(
+        "{:<fw$}  part  {:<aw$}  time\n",
+        "file",
+        "answer",
+        fw = file_width,
+        aw = answer_width
+    );
+    for (file, o, answer) in &rows {
+        summary += &format!(
+            "{:<fw$}  {:>4}  {:<aw$}  {:?}\n",
+            file,
+            o.part.number(),
+            answer,
+            o.elapsed,
+            fw = file_width,
+            aw = answer_width
+        );
+    }
+
+    let failed = solved.iter().filter(|s| is_failure(s)).count();
+    let total = rows.iter().map(|(_, o, _)| o.elapsed).sum::<Duration>();
+    summary += &format!(
+        "{} files, {} failed, total: {:?}\n",
+        solved.len(),
+        failed,
+        total
+    );
+    summary
+}
+
+/// One JSON object per file and part, like the runner's but with the `file` first.
+fn format_json(solved: &[Solved]) -> String {
+    let objects = rows(solved)
+        .map(|(path, o)| {
+            let (answer, error) = match &o.answer {
+                Ok(answer) => (json_string(answer), "null".to_string()),
+                Err(e) => ("null".to_string(), json_string(e)),
+            };
+            format!(
+                "  {{\"file\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"error\": {}, \"elapsed_ns\": {}}}",
+                json_string(&path.display().to_string()),
+                o.day,
+                o.part.number(),
+                answer,
+                error,
+                o.elapsed.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    json_array(&objects)
+}
+
+fn format_csv(solved: &[Solved]) -> String {
+    let mut csv = String::from("file,day,part,answer,error,elapsed_ns\n");
+    for (path, o) in rows(solved) {
+        let (answer, error) = match &o.answer {
+            Ok(answer) => (answer.as_str(), ""),
+            Err(e) => ("", e.as_str()),
+        };
+        csv += &format!(
+            "{},{},{},{},{},{}\n",
+            csv_field(&path.display().to_string()),
+            o.day,
+            o.part.number(),
+            csv_field(answer),
+            csv_field(error),
+            o.elapsed.as_nanos()
+        );
+    }
+    csv
+}
+
+/// The full errors of the failed files, once per file when both parts failed alike.
+pub fn format_errors(solved: &[Solved]) -> String {
+    let mut errors = String::new();
+
+    for s in solved {
+        let mut previous = None;
+        for o in &s.outcomes {
+            if let Err(e) = &o.answer {
+                if previous != Some(e) {
+                    errors += &format!("{} part {}:\n{}\n", s.path.display(), o.part.number(), e);
+                }
+                previous = Some(e);
+            }
+        }
+    }
+
+    errors
+}
+
+pub fn is_failure(solved: &Solved) -> bool {
+    solved.outcomes.iter().any(|o| o.answer.is_err())
+}
+
+fn rows(solved: &[Solved]) -> impl Iterator<Item = (&Path, &Outcome)> {
+    solved
+        .iter()
+        .flat_map(|s| s.outcomes.iter().map(move |o| (s.path.as_path(), o)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_common::num::Arithmetic;
+
+    fn dir() -> PathBuf {
+        let dir = std::env::temp_dir().join("aoc-batch-test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.txt"), "1721\n979\n366\n299\n675\n1456\n").unwrap();
+        fs::write(dir.join("a.txt"), "1721\nx\n").unwrap();
+        fs::write(dir.join("c.txt"), "1010\n1010\n1\n").unwrap();
+        fs::write(dir.join(ANSWERS_FILE_NAME), "part_one = \"1\"\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn batch_test() {
+        let dir = dir();
+        let options = BatchOptions {
+            day: 1,
+            dir: dir.clone(),
+            parts: vec![Part::One],
+            jobs: Some(2),
+            format: Format::Human,
+            arithmetic: Arithmetic::Checked,
+        };
+
+        let solved = batch(&options).unwrap();
+        assert_eq!(
+            solved
+                .iter()
+                .map(|s| (
+                    s.path.file_name().unwrap().to_str().unwrap(),
+                    s.outcomes[0].answer.is_ok()
+                ))
+                .collect::<Vec<_>>(),
+            vec![("a.txt", false), ("b.txt", true), ("c.txt", true)]
+        );
+        assert_eq!(solved[1].outcomes[0].answer, Ok("514579".to_string()));
+        assert_eq!(solved.iter().filter(|s| is_failure(s)).count(), 1);
+
+        let missing = BatchOptions {
+            dir: dir.join("nowhere"),
+            ..options
+        };
+        assert!(batch(&missing).is_err());
+    }
+
+    #[test]
+    fn format_batch_test() {
+        let solved = vec![
+            Solved {
+                path: PathBuf::from("in/a.txt"),
+                outcomes: vec![Outcome {
+                    day: 1,
+                    part: Part::One,
+                    answer: Err("error: expected a number\n --> line 2".to_string()),
+                    elapsed: Duration::from_millis(1),
+                }],
+            },
+            Solved {
+                path: PathBuf::from("in/b.txt"),
+                outcomes: vec![Outcome {
+                    day: 1,
+                    part: Part::One,
+                    answer: Ok("514579".to_string()),
+                    elapsed: Duration::from_millis(2),
+                }],
+            },
+        ];
+
+        assert_eq!(
+            format_batch(&solved, Format::Human),
+            "file      part  answer                    time
+in/a.txt     1  error: expected a number  1ms
+in/b.txt     1  514579                    2ms
+2 files, 1 failed, total: 3ms
+"
+        );
+        assert_eq!(
+            format_batch(&solved, Format::Csv),
+            "file,day,part,answer,error,elapsed_ns
+in/a.txt,1,1,,\"error: expected a number\n --> line 2\",1000000
+in/b.txt,1,1,514579,,2000000
+"
+        );
+        assert_eq!(
+            format_errors(&solved),
+            "in/a.txt part 1:\nerror: expected a number\n --> line 2\n"
+        );
+    }
+}
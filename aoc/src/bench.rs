@@ -0,0 +1,258 @@
+use crate::cli::BenchOptions;
+use crate::days;
+use aoc_common::bench::{Phase, Stats};
+use aoc_common::error::{parse_lines, parse_number, Error, ParseError};
+use aoc_common::input::Source;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// The timings of one phase of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Benchmarks the selected days, returning the measurements and the days that failed.
+pub fn bench(options: &BenchOptions) -> (Vec<Measurement>, Vec<(u8, String)>) {
+    let mut measurements = Vec::new();
+    let mut failures = Vec::new();
+
+    for day in options.days.clone().filter_map(days::get) {
+        let source = match &options.input {
+            Some(path) => Ok(Source::from_arg(path)),
+            None => day.source(),
+        };
+
+        let timings = source
+            .and_then(|source| source.read())
+            .map_err(|e| format!("Cannot read input: {}", e))
+            .and_then(|input| day.bench(&input, options.iterations));
+
+        match timings {
+            Ok(timings) => {
+                measurements.extend(timings.into_iter().map(|(phase, stats)| Measurement {
+                    day: day.number,
+                    phase,
+                    stats,
+                }))
+            }
+            Err(e) => failures.push((day.number, e)),
+        }
+    }
+
+    (measurements, failures)
+}
+
+const HEADER: &str = "# day phase min_ns median_ns max_ns";
+
+/// Writes the measurements as one `day phase min median max` line each, in nanoseconds.
+pub fn save(measurements: &[Measurement], path: &Path) -> io::Result<()> {
+    let mut text = format!("{}\n", HEADER);
+    for m in measurements {
+        text += &format!(
+            "{} {} {} {} {}\n",
+            m.day,
+            m.phase,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.max.as_nanos()
+        );
+    }
+    fs::write(path, text)
+}
+
+/// Reads measurements written by `save`.
+pub fn load(path: &Path) -> Result<Vec<Measurement>, Error> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse(&text)?)
+}
+
+fn parse(text: &str) -> Result<Vec<Measurement>, ParseError> {
+    let measurements = parse_lines(text, |l| {
+        if l.trim().is_empty() || l.starts_with('#') {
+            return Ok(None);
+        }
+
+        let fields = l.split_whitespace().collect::<Vec<_>>();
+        let (day, phase, min, median, max) = match fields[..] {
+            [day, phase, min, median, max] => (day, phase, min, median, max),
+            _ => return Err(ParseError::new(l, l, "`day phase min_ns median_ns max_ns`")),
+        };
+        let nanos = |n| parse_number(l, n).map(Duration::from_nanos);
+
+        Ok(Some(Measurement {
+            day: parse_number(l, day)?,
+            phase: phase
+                .parse()
+                .map_err(|_| ParseError::new(l, phase, "`parse`, `part_one` or `part_two`"))?,
+            stats: Stats {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                max: nanos(max)?,
+            },
+        }))
+    })?;
+
+    Ok(measurements.into_iter().flatten().collect())
+}
+
+/// Relative change of the median from `baseline` to `current`, e.g. `0.25` when 25% slower.
+fn change(baseline: &Stats, current: &Stats) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    if baseline == 0.0 {
+        0.0
+    } else {
+        current.median.as_secs_f64() / baseline - 1.0
+    }
+}
+
+/// The measurements whose median got slower than the baseline by more than `threshold`
+/// percent.
+pub fn regressions<'a>(
+    measurements: &'a [Measurement],
+    baseline: &[Measurement],
+    threshold: f64,
+) -> Vec<&'a Measurement> {
+    measurements
+        .iter()
+        .filter(|m| {
+            find(baseline, m).is_some_and(|b| change(&b.stats, &m.stats) * 100.0 > threshold)
+        })
+        .collect()
+}
+
+fn find<'a>(baseline: &'a [Measurement], m: &Measurement) -> Option<&'a Measurement> {
+    baseline
+        .iter()
+        .find(|b| b.day == m.day && b.phase == m.phase)
+}
+
+pub fn format_report(
+    measurements: &[Measurement],
+    baseline: Option<&[Measurement]>,
+    threshold: f64,
+) -> String {
+    let rows = measurements
+        .iter()
+        .map(|m| {
+            let mut row = vec![
+                format!("{:>3}", m.day),
+                m.phase.to_string(),
+                format!("{:?}", m.stats.min),
+                format!("{:?}", m.stats.median),
+                format!("{:?}", m.stats.max),
+            ];
+            if let Some(baseline) = baseline {
+                match find(baseline, m) {
+                    Some(b) => {
+                        let change = change(&b.stats, &m.stats) * 100.0;
+                        row.push(format!("{:?}", b.stats.median));
+                        row.push(if change > threshold {
+                            format!("{:+.1}% regression", change)
+                        } else {
+                            format!("{:+.1}%", change)
+                        });
+                    }
+                    None => row.extend(vec!["-".to_string(), "new".to_string()]),
+                }
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let mut header = vec!["day", "phase", "min", "median", "max"];
+    if baseline.is_some() {
+        header.extend(&["baseline", "change"]);
+    }
+
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .map(|r| r[i].chars().count())
+                .chain(std::iter::once(h.len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let format_row = |cells: Vec<&str>| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, &w)| format!("{:<w$}", c, w = w))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut report = format_row(header);
+    for row in &rows {
+        report += &format_row(row.iter().map(String::as_str).collect());
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn measurement(day: u8, phase: Phase, median: u64) -> Measurement {
+        Measurement {
+            day,
+            phase,
+            stats: Stats {
+                min: Duration::from_micros(median - 1),
+                median: Duration::from_micros(median),
+                max: Duration::from_micros(median + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn save_and_load_test() {
+        let path = std::env::temp_dir().join("aoc-bench-test.txt");
+        let measurements = vec![
+            measurement(1, Phase::Parse, 10),
+            measurement(1, Phase::PartTwo, 2000),
+        ];
+
+        save(&measurements, &path).unwrap();
+        assert_eq!(load(&path).unwrap(), measurements);
+
+        let e = parse("1 parse 1 2 3\n1 part_three 1 2 3").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "part_three"));
+    }
+
+    #[test]
+    fn compare_test() {
+        let baseline = vec![
+            measurement(1, Phase::Parse, 10),
+            measurement(1, Phase::PartOne, 100),
+        ];
+        let measurements = vec![
+            measurement(1, Phase::Parse, 20),
+            measurement(1, Phase::PartOne, 105),
+            measurement(1, Phase::PartTwo, 5),
+        ];
+
+        assert_eq!(
+            regressions(&measurements, &baseline, 10.0),
+            vec![&measurements[0]]
+        );
+        assert_eq!(
+            format_report(&measurements, Some(&baseline), 10.0),
+            "day  phase     min    median  max    baseline  change
+  1  parse     19µs   20µs    21µs   10µs      +100.0% regression
+  1  part_one  104µs  105µs   106µs  100µs     +5.0%
+  1  part_two  4µs    5µs     6µs    -         new
+"
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use crate::days;
+use aoc_common::day::Part;
+use aoc_common::generate;
+use aoc_common::input::STDIN_ARG;
+use aoc_common::num::Arithmetic;
+use aoc_common::output::Format;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "Usage:
+    aoc run [--day N | --day FIRST-LAST] [--part 1|2] [--input PATH] [--format human|json|csv]
+            [--arithmetic checked|big]
+    aoc verify [--day N | --day FIRST-LAST] [--part 1|2] [--input PATH]
+    aoc record [--day N | --day FIRST-LAST] [--part 1|2] [--input PATH]
+    aoc bench [--day N | --day FIRST-LAST] [--input PATH] [--iterations N]
+              [--save PATH] [--baseline PATH] [--threshold PERCENT]
+    aoc generate --day N [--seed N] [--size N] [--solvable] [--output PATH]
+    aoc batch --day N --dir PATH [--part 1|2] [--jobs N] [--format human|json|csv]
+              [--arithmetic checked|big]
+
+run solves the selected days, verify checks their answers against the ones recorded in
+answers.toml next to each input, and record writes the current answers there.
+
+bench times parsing and both parts separately over --iterations runs (10 by default),
+reporting the min, median and max of each. --save writes the timings to a file, which a later
+run can compare against with --baseline: medians slower by more than --threshold percent (10 by
+default) are flagged as regressions.
+
+generate writes a random input for a day, the same --seed (2020 by default) always giving the
+same input. --size scales it in the day's own unit, e.g. lines or passports, and --solvable
+guarantees that both parts have an answer. The input is printed unless --output is given.
+
+batch solves every file of --dir as an input of the day, on --jobs threads (one per CPU by
+default), and prints the answers of each file. A file that fails to read, parse or solve only
+fails its own rows.
+
+--format prints the answers of run and batch as a table (the default), JSON or CSV, each with the day,
+part, answer or error, and elapsed time.
+
+--arithmetic big solves run, verify, record and batch with integers of any size, in the days
+whose answers can outgrow native integers; the other days are solved as usual. By default,
+those days fail on overflow instead.
+
+Without --day, every day is run in sequence. Without --part, both parts are run.
+--input is only allowed when a single day is selected. For run and bench, --input - reads the
+input from the standard input.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(RunOptions),
+    Record(RunOptions),
+    Bench(BenchOptions),
+    Generate(GenerateOptions),
+    Batch(BatchOptions),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub days: RangeInclusive<u8>,
+    pub parts: Vec<Part>,
+    pub input: Option<PathBuf>,
+    pub format: Format,
+    pub arithmetic: Arithmetic,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            days: days::ALL,
+            parts: Part::ALL.to_vec(),
+            input: None,
+            format: Format::Human,
+            arithmetic: Arithmetic::Checked,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub days: RangeInclusive<u8>,
+    pub input: Option<PathBuf>,
+    pub iterations: usize,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    /// Slowdown of the median, in percent, above which a phase has regressed.
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            days: days::ALL,
+            input: None,
+            iterations: 10,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateOptions {
+    pub day: u8,
+    pub seed: u64,
+    pub generate: generate::Options,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BatchOptions {
+    pub day: u8,
+    /// Directory whose every file is an input of the day.
+    pub dir: PathBuf,
+    pub parts: Vec<Part>,
+    /// Threads solving the files, one per CPU when None.
+    pub jobs: Option<usize>,
+    pub format: Format,
+    pub arithmetic: Arithmetic,
+}
+
+/// Parses the arguments following the program name.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("verify") => parse_answered(args).map(Command::Verify),
+        Some("record") => parse_answered(args).map(Command::Record),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("generate") => parse_generate(args).map(Command::Generate),
+        Some("batch") => parse_batch(args).map(Command::Batch),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(command) => Err(format!("Unknown command: {:?}", command)),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<RunOptions, String> {
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value after {}", arg))
+        };
+
+        match arg.as_str() {
+            "--day" | "-d" => options.days = parse_days(&value()?)?,
+            "--part" | "-p" => options.parts = vec![value()?.parse()?],
+            "--input" | "-i" => options.input = Some(PathBuf::from(value()?)),
+            "--format" | "-f" => options.format = value()?.parse()?,
+            "--arithmetic" | "-a" => options.arithmetic = value()?.parse()?,
+            other => return Err(format!("Unknown option: {:?}", other)),
+        }
+    }
+
+    if options.input.is_some() && options.days.start() != options.days.end() {
+        return Err("--input requires a single --day".to_string());
+    }
+
+    Ok(options)
+}
+
+/// Options of the commands keeping the answers next to the input, which must then be a file.
+fn parse_answered<I: Iterator<Item = String>>(args: I) -> Result<RunOptions, String> {
+    let options = parse_run(args)?;
+    match &options.input {
+        Some(input) if input == Path::new(STDIN_ARG) => Err(
+            "Cannot keep answers next to the standard input: --input must be a file".to_string(),
+        ),
+        _ => Ok(options),
+    }
+}
+
+fn parse_bench<I: Iterator<Item = String>>(mut args: I) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value after {}", arg))
+        };
+
+        match arg.as_str() {
+            "--day" | "-d" => options.days = parse_days(&value()?)?,
+            "--input" | "-i" => options.input = Some(PathBuf::from(value()?)),
+            "--iterations" | "-n" => {
+                let n = value()?;
+                options.iterations = match n.parse() {
+                    Ok(0) | Err(_) => return Err(format!("Invalid iterations: {:?}", n)),
+                    Ok(n) => n,
+                }
+            }
+            "--save" => options.save = Some(PathBuf::from(value()?)),
+            "--baseline" => options.baseline = Some(PathBuf::from(value()?)),
+            "--threshold" => {
+                let t = value()?;
+                options.threshold = t
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|t| *t >= 0.0)
+                    .ok_or_else(|| format!("Invalid threshold: {:?}", t))?
+            }
+            other => return Err(format!("Unknown option: {:?}", other)),
+        }
+    }
+
+    if options.input.is_some() && options.days.start() != options.days.end() {
+        return Err("--input requires a single --day".to_string());
+    }
+
+    Ok(options)
+}
+
+fn parse_generate<I: Iterator<Item = String>>(mut args: I) -> Result<GenerateOptions, String> {
+    let mut day = None;
+    let mut options = GenerateOptions {
+        day: 0,
+        seed: 2020,
+        generate: generate::Options::default(),
+        output: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value after {}", arg))
+        };
+
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let d = value()?;
+                day = Some(
+                    d.parse()
+                        .map_err(|e| format!("Cannot read day {:?}: {}", d, e))?,
+                )
+            }
+            "--seed" | "-s" => {
+                let s = value()?;
+                options.seed = s
+                    .parse()
+                    .map_err(|e| format!("Cannot read seed {:?}: {}", s, e))?
+            }
+            "--size" => {
+                let n = value()?;
+                options.generate.size = Some(
+                    n.parse()
+                        .map_err(|e| format!("Cannot read size {:?}: {}", n, e))?,
+                )
+            }
+            "--solvable" => options.generate.solvable = true,
+            "--output" | "-o" => options.output = Some(PathBuf::from(value()?)),
+            other => return Err(format!("Unknown option: {:?}", other)),
+        }
+    }
+
+    options.day = day.ok_or("generate requires a --day")?;
+    Ok(options)
+}
+
+fn parse_batch<I: Iterator<Item = String>>(mut args: I) -> Result<BatchOptions, String> {
+    let mut day = None;
+    let mut dir = None;
+    let mut options = BatchOptions {
+        day: 0,
+        dir: PathBuf::new(),
+        parts: Part::ALL.to_vec(),
+        jobs: None,
+        format: Format::Human,
+        arithmetic: Arithmetic::Checked,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value after {}", arg))
+        };
+
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let d = value()?;
+                day = Some(
+                    d.parse()
+                        .map_err(|e| format!("Cannot read day {:?}: {}", d, e))?,
+                )
+            }
+            "--dir" => dir = Some(PathBuf::from(value()?)),
+            "--part" | "-p" => options.parts = vec![value()?.parse()?],
+            "--jobs" | "-j" => {
+                let n = value()?;
+                options.jobs = match n.parse() {
+                    Ok(0) | Err(_) => return Err(format!("Invalid jobs: {:?}", n)),
+                    Ok(n) => Some(n),
+                }
+            }
+            "--format" | "-f" => options.format = value()?.parse()?,
+            "--arithmetic" | "-a" => options.arithmetic = value()?.parse()?,
+            other => return Err(format!("Unknown option: {:?}", other)),
+        }
+    }
+
+    options.day = day.ok_or("batch requires a --day")?;
+    options.dir = dir.ok_or("batch requires a --dir")?;
+    Ok(options)
+}
+
+/// Parses `11`, `1-5` or `all` into a range of days.
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |d: &str| {
+        d.parse::<u8>()
+            .map_err(|e| format!("Cannot read day {:?}: {}", d, e))
+    };
+
+    let days = match s {
+        "all" => days::ALL,
+        s => match s.split_once('-') {
+            Some((first, last)) => parse_day(first)?..=parse_day(last)?,
+            None => parse_day(s)?..=parse_day(s)?,
+        },
+    };
+
+    if days.is_empty() {
+        Err(format!("Empty range of days: {:?}", s))
+    } else {
+        Ok(days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(parse_args(args("")), Ok(Command::Help));
+        assert_eq!(
+            parse_args(args("run")),
+            Ok(Command::Run(RunOptions::default()))
+        );
+        assert_eq!(
+            parse_args(args(
+                "run --day 11 --part 2 --input some/file.txt --format json -a big"
+            )),
+            Ok(Command::Run(RunOptions {
+                days: 11..=11,
+                parts: vec![Part::Two],
+                input: Some(PathBuf::from("some/file.txt")),
+                format: Format::Json,
+                arithmetic: Arithmetic::Big,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("verify -d 3-7")),
+            Ok(Command::Verify(RunOptions {
+                days: 3..=7,
+                ..RunOptions::default()
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "bench -d 7 -n 50 --save new.txt --baseline old.txt --threshold 2.5"
+            )),
+            Ok(Command::Bench(BenchOptions {
+                days: 7..=7,
+                iterations: 50,
+                save: Some(PathBuf::from("new.txt")),
+                baseline: Some(PathBuf::from("old.txt")),
+                threshold: 2.5,
+                ..BenchOptions::default()
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "generate -d 8 --seed 7 --size 100 --solvable -o in.txt"
+            )),
+            Ok(Command::Generate(GenerateOptions {
+                day: 8,
+                seed: 7,
+                generate: generate::Options {
+                    size: Some(100),
+                    solvable: true,
+                },
+                output: Some(PathBuf::from("in.txt")),
+            }))
+        );
+        assert_eq!(
+            parse_args(args("batch -d 4 --dir inputs/day04 -j 8 --part 1 -f csv")),
+            Ok(Command::Batch(BatchOptions {
+                day: 4,
+                dir: PathBuf::from("inputs/day04"),
+                parts: vec![Part::One],
+                jobs: Some(8),
+                format: Format::Csv,
+                arithmetic: Arithmetic::Checked,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("run -d 3-7")),
+            Ok(Command::Run(RunOptions {
+                days: 3..=7,
+                ..RunOptions::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_args_errors_test() {
+        assert!(parse_args(args("walk")).is_err());
+        assert!(parse_args(args("run --day")).is_err());
+        assert!(parse_args(args("run --day 7-3")).is_err());
+        assert!(parse_args(args("run --part 3")).is_err());
+        assert!(parse_args(args("run --day 1-2 --input input.txt")).is_err());
+        assert!(parse_args(args("run --format xml")).is_err());
+        assert!(parse_args(args("run --arithmetic float")).is_err());
+        assert!(parse_args(args("verify --day 1 --input -")).is_err());
+        assert!(parse_args(args("bench --iterations 0")).is_err());
+        assert!(parse_args(args("bench --threshold -1")).is_err());
+        assert!(parse_args(args("generate --seed 1")).is_err());
+        assert!(parse_args(args("generate -d 1 --size many")).is_err());
+        assert!(parse_args(args("batch --dir inputs")).is_err());
+        assert!(parse_args(args("batch -d 1")).is_err());
+        assert!(parse_args(args("batch -d 1 --dir inputs --jobs 0")).is_err());
+    }
+}
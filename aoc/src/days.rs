@@ -0,0 +1,59 @@
+use aoc_common::day::Day;
+use std::ops::RangeInclusive;
+
+pub const DAYS: [Day; 19] = [
+    day01::DAY,
+    day02::DAY,
+    day03::DAY,
+    day04::DAY,
+    day05::DAY,
+    day06::DAY,
+    day07::DAY,
+    day08::DAY,
+    day09::DAY,
+    day10::DAY,
+    day11::DAY,
+    day12::DAY,
+    day13::DAY,
+    day14::DAY,
+    day15::DAY,
+    day16::DAY,
+    day17::DAY,
+    day18::DAY,
+    day19::DAY,
+];
+
+/// Every solved day, the range `all` stands for.
+pub const ALL: RangeInclusive<u8> = 1..=DAYS.len() as u8;
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// The days of `numbers`, an error naming the first one not solved yet.
+pub fn select(numbers: RangeInclusive<u8>) -> Result<Vec<&'static Day>, String> {
+    numbers
+        .map(|n| get(n).ok_or_else(|| format!("No day {}", n)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn days_are_in_order_test() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, i + 1);
+        }
+        assert!(get(0).is_none());
+        assert_eq!(get(19).map(|day| day.number), Some(19));
+
+        assert_eq!(select(ALL).map(|days| days.len()), Ok(DAYS.len()));
+        assert_eq!(
+            select(18..=25).map(|days| days.len()),
+            Err("No day 20".to_string())
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::cli::GenerateOptions;
+use crate::days;
+use std::fs;
+
+/// The random input of the selected day.
+pub fn input(options: &GenerateOptions) -> Result<String, String> {
+    let day = days::get(options.day).ok_or_else(|| format!("Unknown day: {}", options.day))?;
+    day.generate(options.seed, &options.generate)
+        .ok_or_else(|| format!("Day {} has no generator", day.number))
+}
+
+/// Writes the random input to the output file, or prints it.
+pub fn generate(options: &GenerateOptions) -> Result<(), String> {
+    let input = input(options)?;
+
+    match &options.output {
+        Some(path) => {
+            fs::write(path, input).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+        }
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_common::day::Part;
+    use aoc_common::generate::Options;
+
+    fn options(day: u8, seed: u64) -> GenerateOptions {
+        GenerateOptions {
+            day,
+            seed,
+            generate: Options {
+                size: None,
+                solvable: true,
+            },
+            output: None,
+        }
+    }
+
+    #[test]
+    fn every_day_solves_its_input_test() {
+        for day in &days::DAYS {
+            let input = input(&options(day.number, 1)).unwrap();
+            assert_eq!(input, super::input(&options(day.number, 1)).unwrap());
+            assert_ne!(input, super::input(&options(day.number, 2)).unwrap());
+
+            for &part in &Part::ALL {
+                // Part two of day 15 takes its time whatever the input.
+                if (day.number, part) != (15, Part::Two) {
+                    let answer = day.solve(part, &input);
+                    assert!(
+                        answer.is_ok(),
+                        "day {} part {:?}: {:?}",
+                        day.number,
+                        part,
+                        answer
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_day_test() {
+        assert!(input(&options(26, 1)).is_err());
+    }
+}
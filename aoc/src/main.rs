@@ -0,0 +1,111 @@
+mod batch;
+mod bench;
+mod cli;
+mod days;
+mod generate;
+mod run;
+mod verify;
+
+use aoc_common::output;
+use cli::Command;
+use std::process;
+
+fn main() {
+    let command = cli::parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, cli::USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Run(options) => {
+            let outcomes = run::run(&options).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+            print!("{}", output::format_outcomes(&outcomes, options.format));
+            eprint!("{}", output::format_errors(&outcomes));
+
+            if outcomes.iter().any(|o| o.answer.is_err()) {
+                process::exit(1);
+            }
+        }
+        Command::Verify(options) => {
+            let verifications = verify::verify(&options);
+            print!("{}", verify::format_report(&verifications));
+
+            if verifications.iter().any(verify::Verification::is_failure) {
+                process::exit(1);
+            }
+        }
+        Command::Bench(options) => {
+            let baseline = options.baseline.as_ref().map(|path| {
+                bench::load(path).unwrap_or_else(|e| {
+                    eprintln!("Cannot read baseline {}: {}", path.display(), e);
+                    process::exit(1);
+                })
+            });
+
+            let (measurements, failures) = bench::bench(&options);
+            print!(
+                "{}",
+                bench::format_report(&measurements, baseline.as_deref(), options.threshold)
+            );
+            for (day, e) in &failures {
+                eprintln!("day {}:\n{}", day, e);
+            }
+
+            if let Some(path) = &options.save {
+                if let Err(e) = bench::save(&measurements, path) {
+                    eprintln!("Cannot save {}: {}", path.display(), e);
+                    process::exit(1);
+                }
+            }
+
+            let regressions = baseline.map_or(0, |baseline| {
+                bench::regressions(&measurements, &baseline, options.threshold).len()
+            });
+            if regressions > 0 {
+                println!("{} regression(s) above {}%", regressions, options.threshold);
+            }
+            if regressions > 0 || !failures.is_empty() {
+                process::exit(1);
+            }
+        }
+        Command::Generate(options) => match generate::generate(&options) {
+            Ok(()) => {}
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        Command::Batch(options) => {
+            let solved = batch::batch(&options).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+            print!("{}", batch::format_batch(&solved, options.format));
+            eprint!("{}", batch::format_errors(&solved));
+
+            if solved.iter().any(batch::is_failure) {
+                process::exit(1);
+            }
+        }
+        Command::Record(options) => {
+            let (outcomes, written) = verify::record(&options);
+            print!("{}", output::format_summary(&outcomes));
+            eprint!("{}", output::format_errors(&outcomes));
+
+            for w in &written {
+                match w {
+                    Ok(path) => println!("recorded {}", path.display()),
+                    Err(e) => eprintln!("{}", e),
+                }
+            }
+
+            if outcomes.iter().any(|o| o.answer.is_err()) || written.iter().any(Result::is_err) {
+                process::exit(1);
+            }
+        }
+    }
+}
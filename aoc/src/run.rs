@@ -0,0 +1,65 @@
+use crate::cli::RunOptions;
+use crate::days;
+use aoc_common::day::Day;
+use aoc_common::input::Source;
+use aoc_common::output::Outcome;
+
+/// The outcomes of the selected days, an error when one of them is not solved yet.
+pub fn run(options: &RunOptions) -> Result<Vec<Outcome>, String> {
+    let days = days::select(options.days.clone())?;
+    Ok(days
+        .into_iter()
+        .flat_map(|day| run_day(day, options))
+        .collect())
+}
+
+pub fn run_day(day: &Day, options: &RunOptions) -> Vec<Outcome> {
+    let source = options.input.as_ref().map(Source::from_arg);
+    let day = day.in_arithmetic(options.arithmetic).unwrap_or(*day);
+    day.run(&options.parts, source)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_common::day::Part;
+
+    #[test]
+    fn run_test() {
+        let options = RunOptions {
+            days: 1..=2,
+            parts: vec![Part::Two],
+            ..RunOptions::default()
+        };
+
+        let outcomes = run(&options).unwrap();
+        assert_eq!(
+            outcomes
+                .iter()
+                .map(|o| (o.day, o.part, o.answer.is_ok()))
+                .collect::<Vec<_>>(),
+            vec![(1, Part::Two, true), (2, Part::Two, true)]
+        );
+    }
+
+    #[test]
+    fn run_missing_input_test() {
+        let options = RunOptions {
+            days: 1..=1,
+            parts: vec![Part::One],
+            input: Some("does/not/exist.txt".into()),
+            ..RunOptions::default()
+        };
+
+        let outcomes = run(&options).unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].answer.is_err());
+
+        let options = RunOptions {
+            days: 20..=25,
+            ..RunOptions::default()
+        };
+        assert_eq!(run(&options), Err("No day 20".to_string()));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::cli::RunOptions;
+use crate::days;
+use crate::run;
+use aoc_common::answers::{Answers, ANSWERS_FILE_NAME};
+use aoc_common::day::{Day, Part};
+use aoc_common::output::Outcome;
+use std::path::PathBuf;
+
+/// How a freshly computed answer compares to the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// The solver failed, or the recorded answers could not be read.
+    Fail(String),
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// Nothing recorded yet for this part.
+    Unrecorded(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Fail(_) | Verdict::Mismatch { .. })
+    }
+}
+
+/// The answers file matching the input the day is solved from: next to `--input` if given.
+fn answers_path(day: &Day, options: &RunOptions) -> PathBuf {
+    match &options.input {
+        Some(input) => input.with_file_name(ANSWERS_FILE_NAME),
+        None => day.answers_path(),
+    }
+}
+
+/// Solves the selected days and checks every answer against the recorded ones.
+pub fn verify(options: &RunOptions) -> Vec<Verification> {
+    options
+        .days
+        .clone()
+        .filter_map(days::get)
+        .flat_map(|day| {
+            let answers = Answers::load(&answers_path(day, options));
+
+            run::run_day(day, options)
+                .into_iter()
+                .map(move |o| Verification {
+                    day: o.day,
+                    part: o.part,
+                    verdict: match &answers {
+                        Ok(answers) => judge(answers.get(o.part), o.answer),
+                        Err(e) => Verdict::Fail(format!("Cannot read answers: {}", e)),
+                    },
+                })
+        })
+        .collect()
+}
+
+fn judge(expected: Option<&str>, actual: Result<String, String>) -> Verdict {
+    match (expected, actual) {
+        (_, Err(e)) => Verdict::Fail(e),
+        (None, Ok(actual)) => Verdict::Unrecorded(actual),
+        (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+        (Some(expected), Ok(actual)) => Verdict::Mismatch {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+/// Solves the selected days and records their answers, keeping the other recorded parts.
+///
+/// Returns the outcomes, failed ones being left unrecorded, with the files written.
+pub fn record(options: &RunOptions) -> (Vec<Outcome>, Vec<Result<PathBuf, String>>) {
+    let mut outcomes = Vec::new();
+    let mut written = Vec::new();
+
+    for day in options.days.clone().filter_map(days::get) {
+        let path = answers_path(day, options);
+        let day_outcomes = run::run_day(day, options);
+
+        if day_outcomes.iter().any(|o| o.answer.is_ok()) {
+            let saved = Answers::load(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|mut answers| {
+                    for o in &day_outcomes {
+                        if let Ok(answer) = &o.answer {
+                            answers.set(o.part, answer.clone());
+                        }
+                    }
+                    answers.save(&path).map_err(|e| e.to_string())
+                });
+            written.push(
+                saved
+                    .map(|_| path.clone())
+                    .map_err(|e| format!("Cannot record {}: {}", path.display(), e)),
+            );
+        }
+
+        outcomes.extend(day_outcomes);
+    }
+
+    (outcomes, written)
+}
+
+pub fn format_report(verifications: &[Verification]) -> String {
+    let rows = verifications
+        .iter()
+        .map(|v| match &v.verdict {
+            Verdict::Pass => ("pass", String::new()),
+            Verdict::Fail(e) => ("fail", e.lines().next().unwrap_or_default().to_string()),
+            Verdict::Mismatch { expected, actual } => (
+                "mismatch",
+                format!("expected {}, found {}", expected, actual),
+            ),
+            Verdict::Unrecorded(actual) => ("unrecorded", format!("found {}", actual)),
+        })
+        .collect::<Vec<_>>();
+
+    let mut report = String::from("day  part  status      detail\n");
+    for (v, (status, detail)) in verifications.iter().zip(&rows) {
+        report += format!(
+            "{:>3}  {:>4}  {:<10}  {}",
+            v.day,
+            v.part.number(),
+            status,
+            detail
+        )
+        .trim_end();
+        report += "\n";
+    }
+
+    let count = |status: &str| rows.iter().filter(|(s, _)| *s == status).count();
+    report += &format!(
+        "{} passed, {} failed, {} mismatched, {} unrecorded\n",
+        count("pass"),
+        count("fail"),
+        count("mismatch"),
+        count("unrecorded")
+    );
+    report
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn judge_test() {
+        assert_eq!(judge(Some("42"), Ok("42".to_string())), Verdict::Pass);
+        assert_eq!(
+            judge(Some("42"), Ok("41".to_string())),
+            Verdict::Mismatch {
+                expected: "42".to_string(),
+                actual: "41".to_string()
+            }
+        );
+        assert_eq!(
+            judge(None, Ok("42".to_string())),
+            Verdict::Unrecorded("42".to_string())
+        );
+        assert_eq!(
+            judge(Some("42"), Err("boom".to_string())),
+            Verdict::Fail("boom".to_string())
+        );
+    }
+
+    #[test]
+    fn verify_and_format_report_test() {
+        let dir = std::env::temp_dir().join("aoc-verify-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        std::fs::write(&input, "1721\n979\n366\n299\n675\n1456\n").unwrap();
+        std::fs::write(
+            dir.join(ANSWERS_FILE_NAME),
+            "part_one = \"514579\"\npart_two = \"1\"\n",
+        )
+        .unwrap();
+
+        let options = RunOptions {
+            days: 1..=1,
+            input: Some(input),
+            ..RunOptions::default()
+        };
+        let verifications = verify(&options);
+
+        assert_eq!(
+            format_report(&verifications),
+            "day  part  status      detail
+  1     1  pass
+  1     2  mismatch    expected 1, found 241861950
+1 passed, 0 failed, 1 mismatched, 0 unrecorded
+"
+        );
+        assert!(verifications.iter().any(Verification::is_failure));
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` distinct expenses (200 by default, at most 2019). When solvable, two of them and
+/// three others sum to 2020, the remaining ones being too big to be part of any pair.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(200);
+    let mut expenses = Vec::new();
+
+    if options.solvable {
+        let a = rng.range(1..=1009);
+        let (x, y) = (rng.range(1..=673), rng.range(1..=673));
+        for n in [a, 2020 - a, x, y, 2020 - x - y] {
+            if !expenses.contains(&n) {
+                expenses.push(n);
+            }
+        }
+    }
+
+    let smallest = if options.solvable { 1011 } else { 1 };
+    let mut others = (smallest..=2019)
+        .filter(|n| !expenses.contains(n))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut others);
+    let missing = size.saturating_sub(expenses.len());
+    expenses.extend(others.into_iter().take(missing));
+
+    rng.shuffle(&mut expenses);
+    expenses.iter().map(|n| format!("{}\n", n)).collect()
+}
@@ -0,0 +1,512 @@
+//! Day 1: Report Repair. Finding the expenses that sum to 2020, or to any other target.
+
+use aoc_common::cli::DayOptions;
+use aoc_common::day::{Day, Part};
+use aoc_common::error::{parse_lines, parse_number, ParseError};
+use aoc_common::num::{try_product, try_sum, Arithmetic, BigInt, Integer, Overflow};
+use aoc_common::solution::{found, Solution};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input in the arithmetic of
+/// `N`.
+pub struct Day01<N = i32>(PhantomData<N>);
+
+impl<N: Integer> Solution for Day01<N> {
+    type Input<'a> = Vec<N>;
+    type PartOne = N;
+    type PartTwo = N;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        found(part_one(input, &N::from_u64(TARGET)?)?)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        found(part_two(input, &N::from_u64(TARGET)?)?)
+    }
+}
+
+/// The sum the expenses of the puzzle must reach.
+pub const TARGET: u64 = 2020;
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day01>(1, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_big::<Day01<BigInt>>();
+
+/// The integers to solve with, wider ones for ledgers with large amounts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Integers {
+    I32,
+    I64,
+    I128,
+    U64,
+    U128,
+}
+
+impl FromStr for Integers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "i32" => Ok(Integers::I32),
+            "i64" => Ok(Integers::I64),
+            "i128" => Ok(Integers::I128),
+            "u64" => Ok(Integers::U64),
+            "u128" => Ok(Integers::U128),
+            i => Err(format!(
+                "Unknown integers: {:?}, expected i32, i64, i128, u64 or u128",
+                i
+            )),
+        }
+    }
+}
+
+/// The binary's own options: the target the expenses must sum to, and the integers to solve
+/// with.
+#[derive(Debug, Default, PartialEq)]
+pub struct ReportOptions {
+    pub target: Option<BigInt>,
+    pub integers: Option<Integers>,
+}
+
+impl DayOptions for ReportOptions {
+    const USAGE: &'static str = "
+
+--target N looks for expenses summing to N rather than to 2020.
+
+--integers i32|i64|i128|u64|u128 solves with these integers rather than with i32, failing on
+overflow. --arithmetic big solves with integers of any size instead.";
+
+    fn parse_option(
+        &mut self,
+        arg: &str,
+        value: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--target" | "-t" => self.target = Some(value()?.parse()?),
+            // `-i` being the runner's `--input`.
+            "--integers" | "-w" => self.integers = Some(value()?.parse()?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn solve(
+        &self,
+        _: &Day,
+        arithmetic: Arithmetic,
+        part: Part,
+        input: &str,
+    ) -> Result<String, String> {
+        let target = self.target.clone().unwrap_or_else(|| BigInt::from(TARGET));
+
+        match (arithmetic, self.integers) {
+            (Arithmetic::Big, None) => solve::<BigInt>(part, input, &target),
+            (Arithmetic::Big, Some(_)) => {
+                Err("--integers and --arithmetic big cannot go together".to_string())
+            }
+            (Arithmetic::Checked, None) | (Arithmetic::Checked, Some(Integers::I32)) => {
+                solve::<i32>(part, input, &target)
+            }
+            (Arithmetic::Checked, Some(Integers::I64)) => solve::<i64>(part, input, &target),
+            (Arithmetic::Checked, Some(Integers::I128)) => solve::<i128>(part, input, &target),
+            (Arithmetic::Checked, Some(Integers::U64)) => solve::<u64>(part, input, &target),
+            (Arithmetic::Checked, Some(Integers::U128)) => solve::<u128>(part, input, &target),
+        }
+    }
+}
+
+/// Solves `part` of `input` with expenses summing to `target`, in the integers `N`.
+pub fn solve<N: Integer>(part: Part, input: &str, target: &BigInt) -> Result<String, String> {
+    let target = target
+        .to_string()
+        .parse::<N>()
+        .map_err(|_| format!("The target {} does not fit the integers", target))?;
+    let input = parse::<N>(input).map_err(|e| e.to_string())?;
+
+    let answer = match part {
+        Part::One => part_one(&input, &target)?,
+        Part::Two => part_two(&input, &target)?,
+    };
+    found(answer).map(|answer| answer.to_string())
+}
+
+/// One expense per line.
+pub fn parse<N: Integer>(input: &str) -> Result<Vec<N>, ParseError> {
+    parse_lines(input, |n| parse_number(n, n))
+}
+
+/// The product of the two expenses summing to `target`.
+pub fn part_one<N: Integer>(input: &[N], target: &N) -> Result<Option<N>, Overflow> {
+    k_sum(input, 2, target).map(try_product).transpose()
+}
+
+/// The product of the three expenses summing to `target`.
+pub fn part_two<N: Integer>(input: &[N], target: &N) -> Result<Option<N>, Overflow> {
+    k_sum(input, 3, target).map(try_product).transpose()
+}
+
+/// `k` entries of `input` at distinct indices summing to `target`, smallest first.
+///
+/// The entries are sorted, then all but the last two are fixed in turn and the last two found by
+/// two pointers closing in from both ends, in O(n^(k-1)) for k >= 2. Entries whose remaining
+/// sum does not fit `N` cannot complete one, and are passed over.
+pub fn k_sum<N: Integer>(input: &[N], k: usize, target: &N) -> Option<Vec<N>> {
+    let mut sorted = input.to_vec();
+    sorted.sort();
+
+    let mut chosen = Vec::with_capacity(k);
+    k_sum_sorted(&sorted, k, target, &mut chosen).then_some(chosen)
+}
+
+/// Whether `k` entries of `sorted` sum to `target`, pushing them onto `chosen` if so.
+fn k_sum_sorted<N: Integer>(sorted: &[N], k: usize, target: &N, chosen: &mut Vec<N>) -> bool {
+    match k {
+        0 => *target == N::zero(),
+        1 => {
+            let found = sorted.binary_search(target).is_ok();
+            if found {
+                chosen.push(target.clone());
+            }
+            found
+        }
+        2 => {
+            let (mut i, mut j) = (0, sorted.len().saturating_sub(1));
+            while i < j {
+                match compare_sum(&sorted[i], &sorted[j], target) {
+                    Ordering::Less => i += 1,
+                    Ordering::Greater => j -= 1,
+                    Ordering::Equal => {
+                        chosen.extend([sorted[i].clone(), sorted[j].clone()]);
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+        k => {
+            for (i, first) in sorted.iter().enumerate() {
+                // An entry equal to the previous one has fewer entries after it to choose from.
+                if i > 0 && sorted[i - 1] == *first {
+                    continue;
+                }
+                // Past the target, with only entries as large after it.
+                if first > target && *first >= N::zero() {
+                    break;
+                }
+
+                // No entries can sum to a remainder that does not fit.
+                let rest = match target.try_sub(first) {
+                    Ok(rest) => rest,
+                    Err(_) => continue,
+                };
+
+                chosen.push(first.clone());
+                if k_sum_sorted(&sorted[i + 1..], k - 1, &rest, chosen) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+    }
+}
+
+/// How `a + b` compares to `target`, a sum too large or too small for `N` being beyond it.
+fn compare_sum<N: Integer>(a: &N, b: &N, target: &N) -> Ordering {
+    match a.try_add(b) {
+        Ok(sum) => sum.cmp(target),
+        Err(_) if *a > N::zero() => Ordering::Greater,
+        Err(_) => Ordering::Less,
+    }
+}
+
+/// Every combination of `k` entries of `input` summing to `target`, as their indices in
+/// increasing order. Equal entries at different indices make different combinations.
+pub fn k_sums<N: Integer>(input: &[N], k: usize, target: &N) -> KSums<N> {
+    let mut sorted = input.iter().cloned().zip(0..).collect::<Vec<_>>();
+    sorted.sort();
+
+    KSums {
+        sorted,
+        k,
+        target: target.clone(),
+        chosen: Vec::new(),
+        last: 0..0,
+        started: false,
+        done: false,
+    }
+}
+
+/// How many combinations `k_sums` would yield, without building them.
+pub fn count_k_sums<N: Integer>(input: &[N], k: usize, target: &N) -> usize {
+    let mut sums = k_sums(input, k, target);
+    let mut count = 0;
+    while sums.next_combination() {
+        // With no entry to choose, the empty combination is the only one.
+        count += if k == 0 { 1 } else { sums.last.len() };
+    }
+    count
+}
+
+/// The iterator of `k_sums`.
+///
+/// It goes through the combinations of all but the last entry in the order of their values,
+/// the entries completing each one to `target` being a run of equal values found by binary
+/// search. Combinations whose sum, or the entry completing it, does not fit `N` are passed
+/// over.
+#[derive(Debug, Clone)]
+pub struct KSums<N> {
+    /// The entries with their indices, by value.
+    sorted: Vec<(N, usize)>,
+    k: usize,
+    target: N,
+    /// Positions in `sorted` of all but the last entry of the current combinations.
+    chosen: Vec<usize>,
+    /// Positions of the last entries completing `chosen`, still to yield.
+    last: Range<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<N: Integer> KSums<N> {
+    /// Moves to the next `chosen` having entries to complete it, false once there are none.
+    fn next_combination(&mut self) -> bool {
+        if self.done || self.k == 0 {
+            let first = !self.done;
+            self.done = true;
+            self.last = 0..0;
+            return first && self.target == N::zero();
+        }
+
+        let mut level = if self.started {
+            self.bump(self.chosen.len())
+        } else {
+            self.started = true;
+            self.chosen = (0..self.k - 1).collect();
+            (self.k - 1 <= self.sorted.len()).then_some(0)
+        };
+
+        while let Some(bumped) = level {
+            let sum = match try_sum(self.chosen.iter().map(|&p| self.sorted[p].0.clone())) {
+                Ok(sum) => sum,
+                Err(_) => {
+                    level = self.bump(self.chosen.len());
+                    continue;
+                }
+            };
+            let from = self.chosen.last().map_or(0, |&p| p + 1);
+            let rest = &self.sorted[from..];
+            let smallest = match rest.first() {
+                Some((n, _)) => n,
+                None => {
+                    level = self.bump(self.chosen.len());
+                    continue;
+                }
+            };
+
+            // The entries after the bumped one being the smallest left, no greater one at its
+            // level can reach the target either.
+            if compare_sum(&sum, smallest, &self.target) == Ordering::Greater {
+                level = self.bump(bumped);
+                continue;
+            }
+
+            if let Ok(need) = self.target.try_sub(&sum) {
+                let start = from + rest.partition_point(|(n, _)| *n < need);
+                let end = from + rest.partition_point(|(n, _)| *n <= need);
+                if start < end {
+                    self.last = start..end;
+                    return true;
+                }
+            }
+            level = self.bump(self.chosen.len());
+        }
+
+        self.done = true;
+        false
+    }
+
+    /// Moves the rightmost of the first `levels` chosen positions that can move one step
+    /// further, the ones after it following it, returning its level.
+    fn bump(&mut self, levels: usize) -> Option<usize> {
+        let n = self.sorted.len();
+        let slots = self.chosen.len() + 1;
+        let level = (0..levels)
+            .rev()
+            .find(|&i| self.chosen[i] + slots - i < n)?;
+
+        self.chosen[level] += 1;
+        for i in level + 1..self.chosen.len() {
+            self.chosen[i] = self.chosen[i - 1] + 1;
+        }
+        Some(level)
+    }
+}
+
+impl<N: Integer> Iterator for KSums<N> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(last) = self.last.next() {
+                let mut indices = self
+                    .chosen
+                    .iter()
+                    .chain(Some(&last))
+                    .map(|&p| self.sorted[p].1)
+                    .collect::<Vec<_>>();
+                indices.sort_unstable();
+                return Some(indices);
+            }
+
+            match self.next_combination() {
+                true if self.k == 0 => return Some(Vec::new()),
+                true => {}
+                false => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parts_test() {
+        let input = parse::<i32>("1721\n979\n366\n299\n675\n1456\n").unwrap();
+        assert_eq!(part_one(&input, &2020), Ok(Some(514579)));
+        assert_eq!(part_two(&input, &2020), Ok(Some(241861950)));
+        assert_eq!(part_one(&input, &1), Ok(None));
+    }
+
+    #[test]
+    fn k_sum_test() {
+        let input = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(k_sum(&input, 2, &2020), Some(vec![299, 1721]));
+        assert_eq!(k_sum(&input, 4, &2319), Some(vec![299, 366, 675, 979]));
+        assert_eq!(k_sum(&input, 1, &979), Some(vec![979]));
+        assert_eq!(k_sum(&input, 0, &0), Some(vec![]));
+        assert_eq!(k_sum(&input, 7, &0), None);
+
+        // Every entry is used at most once, however it could complete the sum.
+        assert_eq!(k_sum(&[1010, 3], 2, &2020), None);
+        assert_eq!(k_sum(&[673, 1], 3, &2019), None);
+        assert_eq!(k_sum(&[673, 673, 673], 3, &2019), Some(vec![673; 3]));
+        assert_eq!(k_sum(&[-5, 2, 10, -3], 3, &2), Some(vec![-5, -3, 10]));
+
+        // An entry leaving a remainder too large for the integers cannot be part of the sum.
+        assert_eq!(k_sum(&[i32::MIN, 1, 2, 3], 3, &6), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn k_sums_test() {
+        let input = [1721, 979, 366, 299, 675, 1456, 299, 1010, 1010, 1010];
+        assert_eq!(
+            k_sums(&input, 2, &2020).collect::<Vec<_>>(),
+            vec![vec![0, 3], vec![0, 6], vec![7, 8], vec![7, 9], vec![8, 9]]
+        );
+        assert_eq!(count_k_sums(&input, 2, &2020), 5);
+        assert_eq!(count_k_sums(&input, 3, &2020), 1);
+        assert_eq!(count_k_sums(&input, 3, &3030), 7);
+        assert_eq!(k_sums(&input, 0, &0).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(count_k_sums(&input, 0, &0), 1);
+        assert_eq!(k_sums(&input, 11, &0).next(), None);
+
+        // Against every triple of small entries, some equal and some negative.
+        let input = (0..24).map(|i| (i * 7 % 11) - 4).collect::<Vec<i32>>();
+        for target in -12..=18 {
+            let mut expected = Vec::new();
+            for a in 0..input.len() {
+                for b in a + 1..input.len() {
+                    for c in b + 1..input.len() {
+                        if input[a] + input[b] + input[c] == target {
+                            expected.push(vec![a, b, c]);
+                        }
+                    }
+                }
+            }
+            let mut all = k_sums(&input, 3, &target).collect::<Vec<_>>();
+            all.sort();
+            assert_eq!(all, expected);
+            assert_eq!(count_k_sums(&input, 3, &target), expected.len());
+        }
+
+        // Any 3 of 30 equal entries.
+        assert_eq!(count_k_sums(&[1; 30], 3, &3), 4060);
+        assert_eq!(count_k_sums(&[1; 30], 3, &4), 0);
+
+        // Next to combinations whose sum or missing entry does not fit the integers.
+        let input = [i32::MIN, 1, 2, 3, i32::MIN];
+        assert_eq!(
+            k_sums(&input, 3, &6).collect::<Vec<_>>(),
+            vec![vec![1, 2, 3]]
+        );
+        assert_eq!(count_k_sums(&input[..4], 3, &6), 1);
+    }
+
+    #[test]
+    fn solve_test() {
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+        let options = ReportOptions::default();
+        assert_eq!(
+            options.solve(&DAY, Arithmetic::Checked, Part::One, input),
+            Ok("514579".to_string())
+        );
+
+        let options = ReportOptions {
+            target: Some(BigInt::from(4156u64)),
+            integers: Some(Integers::U128),
+        };
+        assert_eq!(
+            options.solve(&DAY, Arithmetic::Checked, Part::Two, input),
+            Ok("2453154704".to_string())
+        );
+        assert!(options
+            .solve(&DAY, Arithmetic::Big, Part::Two, input)
+            .is_err());
+
+        let options = ReportOptions {
+            target: Some("-1".parse().unwrap()),
+            integers: Some(Integers::U64),
+        };
+        assert_eq!(
+            options.solve(&DAY, Arithmetic::Checked, Part::One, input),
+            Err("The target -1 does not fit the integers".to_string())
+        );
+
+        let mut options = ReportOptions::default();
+        let mut value = || Ok("u64".to_string());
+        assert_eq!(options.parse_option("-i", &mut value), Ok(false));
+        assert_eq!(options.parse_option("-w", &mut value), Ok(true));
+        assert_eq!(options.integers, Some(Integers::U64));
+    }
+
+    #[test]
+    fn overflow_test() {
+        let input = "1000000\n2000000\n1020\n";
+        assert_eq!(
+            part_one(&parse::<i32>(input).unwrap(), &3000000),
+            Err(Overflow)
+        );
+        assert_eq!(
+            part_one(&parse::<BigInt>(input).unwrap(), &BigInt::from(3000000u64)),
+            Ok(Some(BigInt::from(2000000000000u64)))
+        );
+        assert_eq!(
+            part_two::<i64>(&parse(input).unwrap(), &3001020),
+            Ok(Some(2040000000000000))
+        );
+    }
+}
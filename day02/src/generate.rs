@@ -0,0 +1,21 @@
+use aoc_common::generate::{Options, Rng};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// `size` password entries (1000 by default), every policy fitting in its password. Both parts
+/// always have an answer, possibly 0.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    (0..options.size_or(1000))
+        .map(|_| {
+            let letter = *rng.choose(LETTERS) as char;
+            let len = rng.range(4..=20) as usize;
+            // A few letters to choose from, so that the policy letter shows up often.
+            let alphabet = [letter as u8, *rng.choose(LETTERS), *rng.choose(LETTERS)];
+            let password = rng.string(&alphabet, len);
+
+            let lower = rng.range(1..=len as u64 - 1);
+            let upper = rng.range(lower + 1..=len as u64);
+            format!("{}-{} {}: {}\n", lower, upper, letter, password)
+        })
+        .collect()
+}
@@ -0,0 +1,706 @@
+//! Day 2: Password Philosophy. Checking passwords against the policy in effect when they were set.
+
+use aoc_common::cli::DayOptions;
+use aoc_common::day::{Day, Part};
+use aoc_common::error::{parse_lines, parse_number, ParseError};
+use aoc_common::num::Arithmetic;
+use aoc_common::output::{csv_field, json_array, json_string, Format};
+use aoc_common::solution::Solution;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<PasswordEntry<'a>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day02>(2, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// How many passwords have their letter the allowed number of times.
+pub fn part_one(entries: &[PasswordEntry]) -> usize {
+    count_valid(entries, &SledRental)
+}
+
+/// How many passwords have their letter at exactly one of the two positions.
+pub fn part_two(entries: &[PasswordEntry]) -> usize {
+    count_valid(entries, &Toboggan::default())
+}
+
+/// How many passwords follow `policy`.
+pub fn count_valid(entries: &[PasswordEntry], policy: &dyn PasswordPolicy) -> usize {
+    entries.iter().filter(|e| policy.is_valid(e)).count()
+}
+
+/// A line like `1-3 a: abcde`: the policy in effect when the password was set, then the
+/// password.
+///
+/// Its bounds are positions counting from 1, the lower one first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordEntry<'a> {
+    lower: usize,
+    upper: usize,
+    letter: char,
+    password: &'a str,
+}
+
+impl<'a> PasswordEntry<'a> {
+    /// The entry, or what its bounds should have been.
+    pub fn new(
+        lower: usize,
+        upper: usize,
+        letter: char,
+        password: &'a str,
+    ) -> Result<PasswordEntry<'a>, String> {
+        if lower == 0 {
+            Err("a range from 1 like `1-3`".to_string())
+        } else if lower > upper {
+            Err("a range with its lower bound first like `1-3`".to_string())
+        } else {
+            Ok(PasswordEntry {
+                lower,
+                upper,
+                letter,
+                password,
+            })
+        }
+    }
+
+    pub fn lower(&self) -> usize {
+        self.lower
+    }
+
+    pub fn upper(&self) -> usize {
+        self.upper
+    }
+
+    pub fn letter(&self) -> char {
+        self.letter
+    }
+
+    pub fn password(&self) -> &'a str {
+        self.password
+    }
+}
+
+/// One entry per line.
+pub fn parse(input: &str) -> Result<Vec<PasswordEntry<'_>>, ParseError> {
+    parse_lines(input, parse_line)
+}
+
+fn parse_line(input: &str) -> Result<PasswordEntry<'_>, ParseError> {
+    let end = &input[input.len()..];
+    let mut words = input.split_whitespace();
+
+    let rule = words
+        .next()
+        .ok_or_else(|| ParseError::new(input, end, "a policy like `1-3 a:`"))?;
+    let (lower, upper) = rule
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(input, rule, "a range like `1-3`"))?;
+    let lower = parse_number(input, lower)?;
+    let upper = parse_number(input, upper)?;
+
+    let field = words
+        .next()
+        .ok_or_else(|| ParseError::new(input, end, "a letter like `a:`"))?;
+    let mut chars = field.chars();
+    let letter = match (chars.next(), chars.as_str()) {
+        (Some(letter), ":") => letter,
+        _ => return Err(ParseError::new(input, field, "a letter like `a:`")),
+    };
+    let password = words
+        .next()
+        .ok_or_else(|| ParseError::new(input, end, "a password"))?;
+    if let Some(extra) = words.next() {
+        return Err(ParseError::new(input, extra, "the end of the line"));
+    }
+
+    PasswordEntry::new(lower, upper, letter, password)
+        .map_err(|expected| ParseError::new(input, rule, expected))
+}
+
+/// A rule passwords are checked against, possibly reading the policy line of their entry.
+pub trait PasswordPolicy {
+    /// Why the password of `entry` breaks the policy, if it does.
+    fn check(&self, entry: &PasswordEntry) -> Result<(), String>;
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        self.check(entry).is_ok()
+    }
+}
+
+/// The sled rental policy: the letter appears between `lower` and `upper` times.
+#[derive(Debug, Clone, Copy)]
+pub struct SledRental;
+
+impl PasswordPolicy for SledRental {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), String> {
+        let count = entry
+            .password
+            .chars()
+            .filter(|&c| c == entry.letter)
+            .count();
+
+        if entry.lower <= count && count <= entry.upper {
+            Ok(())
+        } else {
+            Err(format!(
+                "char {:?} appears {} times, allowed {}-{}",
+                entry.letter, count, entry.lower, entry.upper
+            ))
+        }
+    }
+}
+
+/// What the positions of an entry count.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Positions {
+    /// Unicode scalar values, the characters a person reading the password counts.
+    #[default]
+    Chars,
+    /// Bytes of the UTF-8 encoding, a letter being at the byte its encoding starts at.
+    Bytes,
+}
+
+impl Positions {
+    /// Whether `letter` is at `position` of `password`, counting from 1. Nothing is past its
+    /// end.
+    pub fn letter_at(self, password: &str, position: usize, letter: char) -> bool {
+        let Some(index) = position.checked_sub(1) else {
+            return false;
+        };
+
+        match self {
+            Positions::Chars => password.chars().nth(index) == Some(letter),
+            Positions::Bytes => password
+                .get(index..)
+                .is_some_and(|rest| rest.starts_with(letter)),
+        }
+    }
+}
+
+/// The toboggan policy: the letter is at exactly one of the positions `lower` and `upper`,
+/// counting from 1.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Toboggan(pub Positions);
+
+impl PasswordPolicy for Toboggan {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), String> {
+        let at_lower = self.0.letter_at(entry.password, entry.lower, entry.letter);
+        let at_upper = self.0.letter_at(entry.password, entry.upper, entry.letter);
+
+        match (at_lower, at_upper) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err(format!(
+                "positions {} and {} both match",
+                entry.lower, entry.upper
+            )),
+            (false, false) => Err(format!(
+                "neither position {} nor {} matches",
+                entry.lower, entry.upper
+            )),
+        }
+    }
+}
+
+/// The password never contains the character, whatever its entry says.
+#[derive(Debug, Clone, Copy)]
+pub struct Forbidden(pub char);
+
+impl PasswordPolicy for Forbidden {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), String> {
+        match entry.password.chars().position(|c| c == self.0) {
+            None => Ok(()),
+            Some(i) => Err(format!(
+                "char {:?} is forbidden, found at position {}",
+                self.0,
+                i + 1
+            )),
+        }
+    }
+}
+
+/// No character of the password is repeated more than this many times in a row.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxRepeat(pub usize);
+
+impl PasswordPolicy for MaxRepeat {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), String> {
+        match longest_run(entry.password) {
+            Some((c, run)) if run > self.0 => Err(format!(
+                "char {:?} repeats {} times in a row, allowed {}",
+                c, run, self.0
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The first of the longest runs of the same character, with its length.
+fn longest_run(password: &str) -> Option<(char, usize)> {
+    let mut chars = password.chars().peekable();
+    let mut longest: Option<(char, usize)> = None;
+
+    while let Some(c) = chars.next() {
+        let mut run = 1;
+        while chars.next_if_eq(&c).is_some() {
+            run += 1;
+        }
+        if longest.is_none_or(|(_, l)| run > l) {
+            longest = Some((c, run));
+        }
+    }
+    longest
+}
+
+/// Builds a policy from the argument after its name, if any.
+pub type BuildPolicy = fn(Option<&str>) -> Result<Box<dyn PasswordPolicy>, String>;
+
+/// The policies by name, for picking them from the command line.
+pub struct PolicyRegistry {
+    policies: Vec<(&'static str, BuildPolicy)>,
+}
+
+impl PolicyRegistry {
+    /// A registry without any policy.
+    pub fn new() -> PolicyRegistry {
+        PolicyRegistry {
+            policies: Vec::new(),
+        }
+    }
+
+    /// Registers `build` under `name`, replacing any policy of that name.
+    pub fn register(&mut self, name: &'static str, build: BuildPolicy) {
+        self.policies.retain(|&(n, _)| n != name);
+        self.policies.push((name, build));
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.policies.iter().map(|&(name, _)| name)
+    }
+
+    /// The policy of `spec`, a name followed by `=` and the argument for the policies taking
+    /// one, like `max-repeat=2`.
+    pub fn build(&self, spec: &str) -> Result<Box<dyn PasswordPolicy>, String> {
+        let (name, argument) = match spec.split_once('=') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (spec, None),
+        };
+
+        let &(_, build) = self
+            .policies
+            .iter()
+            .find(|&&(n, _)| n == name)
+            .ok_or_else(|| {
+                let names = self.names().collect::<Vec<_>>().join(", ");
+                format!("Unknown policy: {:?}, expected one of {}", name, names)
+            })?;
+        build(argument).map_err(|e| format!("Invalid policy {:?}: {}", spec, e))
+    }
+}
+
+/// The two policies of the puzzle, `sled-rental` and `toboggan[=chars|bytes]`, then
+/// `forbidden=C` and `max-repeat=N`.
+impl Default for PolicyRegistry {
+    fn default() -> PolicyRegistry {
+        let mut registry = PolicyRegistry::new();
+        registry.register("sled-rental", |argument| {
+            no_argument(argument)?;
+            Ok(Box::new(SledRental))
+        });
+        registry.register("toboggan", |argument| match argument {
+            None | Some("chars") => Ok(Box::new(Toboggan(Positions::Chars))),
+            Some("bytes") => Ok(Box::new(Toboggan(Positions::Bytes))),
+            Some(_) => Err("expected chars or bytes".to_string()),
+        });
+        registry.register("forbidden", |argument| {
+            let mut chars = argument.ok_or("expected a character")?.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Box::new(Forbidden(c))),
+                _ => Err("expected a single character".to_string()),
+            }
+        });
+        registry.register("max-repeat", |argument| {
+            let n = argument.ok_or("expected a number")?;
+            let n = n.parse().map_err(|_| format!("{:?} is not a number", n))?;
+            Ok(Box::new(MaxRepeat(n)))
+        });
+        registry
+    }
+}
+
+fn no_argument(argument: Option<&str>) -> Result<(), String> {
+    match argument {
+        None => Ok(()),
+        Some(_) => Err("expected no argument".to_string()),
+    }
+}
+
+/// The binary's own options: the policies to count the valid passwords of, rather than
+/// solving the puzzle.
+#[derive(Debug, Default, PartialEq)]
+pub struct PolicyOptions {
+    /// The policies as given, like `max-repeat=2`.
+    pub policies: Vec<String>,
+    /// Whether to list the passwords breaking the policies rather than counting them.
+    pub violations: bool,
+}
+
+impl DayOptions for PolicyOptions {
+    const USAGE: &'static str = "
+
+--policy NAME[=ARG], repeatable, counts the passwords valid and invalid under each policy
+instead: sled-rental and toboggan, the policies of the puzzle, toboggan=bytes for positions
+counting bytes rather than characters, forbidden=C for passwords without the character C, and
+max-repeat=N for passwords repeating no character more than N times in a row.
+
+--violations lists every password breaking the policies, the two of the puzzle by default,
+with its line and why.";
+
+    fn parse_option(
+        &mut self,
+        arg: &str,
+        value: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--policy" | "-p" => {
+                let spec = value()?;
+                PolicyRegistry::default().build(&spec)?;
+                self.policies.push(spec);
+                Ok(true)
+            }
+            "--violations" => {
+                self.violations = true;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn solve(&self, day: &Day, _: Arithmetic, part: Part, input: &str) -> Result<String, String> {
+        day.solve(part, input)
+    }
+
+    fn report(&self, input: &str, format: Format) -> Option<Result<String, String>> {
+        if self.violations {
+            let specs = if self.policies.is_empty() {
+                vec!["sled-rental".to_string(), "toboggan".to_string()]
+            } else {
+                self.policies.clone()
+            };
+            let report = parse(input).map_err(|e| e.to_string()).and_then(|entries| {
+                let violations = violations(&entries, &specs)?;
+                Ok(format_violations(&violations, entries.len(), format))
+            });
+            Some(report)
+        } else if !self.policies.is_empty() {
+            Some(policy_counts(input, &self.policies).map(|counts| format_counts(&counts, format)))
+        } else {
+            None
+        }
+    }
+}
+
+/// A password breaking a policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation<'a> {
+    /// The line of the entry, from 1.
+    pub line: usize,
+    pub password: &'a str,
+    /// The policy as given, like `max-repeat=2`.
+    pub policy: String,
+    pub reason: String,
+}
+
+/// Every password of `entries` breaking a policy of `specs`, line by line.
+pub fn violations<'a>(
+    entries: &[PasswordEntry<'a>],
+    specs: &[String],
+) -> Result<Vec<Violation<'a>>, String> {
+    let registry = PolicyRegistry::default();
+    let policies = specs
+        .iter()
+        .map(|spec| Ok((spec, registry.build(spec)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut violations = Vec::new();
+    for (i, entry) in entries.iter().enumerate() {
+        for (spec, policy) in &policies {
+            if let Err(reason) = policy.check(entry) {
+                violations.push(Violation {
+                    line: i + 1,
+                    password: entry.password,
+                    policy: spec.to_string(),
+                    reason,
+                });
+            }
+        }
+    }
+    Ok(violations)
+}
+
+/// The violations one per line, ending with how many of the `total` passwords broke a policy.
+pub fn format_violations(violations: &[Violation], total: usize, format: Format) -> String {
+    match format {
+        Format::Human => {
+            let mut listing = String::new();
+            for v in violations {
+                listing += &format!(
+                    "line {}: {} breaks {}: {}\n",
+                    v.line, v.password, v.policy, v.reason
+                );
+            }
+
+            let mut lines = violations.iter().map(|v| v.line).collect::<Vec<_>>();
+            lines.dedup();
+            listing += &format!(
+                "{} violations, {} of {} passwords invalid\n",
+                violations.len(),
+                lines.len(),
+                total
+            );
+            listing
+        }
+        Format::Json => {
+            let objects = violations
+                .iter()
+                .map(|v| {
+                    format!(
+                        "  {{\"line\": {}, \"password\": {}, \"policy\": {}, \"reason\": {}}}",
+                        v.line,
+                        json_string(v.password),
+                        json_string(&v.policy),
+                        json_string(&v.reason)
+                    )
+                })
+                .collect::<Vec<_>>();
+            json_array(&objects)
+        }
+        Format::Csv => {
+            let mut csv = String::from("line,password,policy,reason\n");
+            for v in violations {
+                csv += &format!(
+                    "{},{},{},{}\n",
+                    v.line,
+                    csv_field(v.password),
+                    csv_field(&v.policy),
+                    csv_field(&v.reason)
+                );
+            }
+            csv
+        }
+    }
+}
+
+/// How many passwords follow a policy, and how many do not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyCount {
+    /// The policy as given, like `max-repeat=2`.
+    pub policy: String,
+    pub valid: usize,
+    pub invalid: usize,
+}
+
+/// The valid and invalid passwords of `input` under each policy of `specs`.
+pub fn policy_counts(input: &str, specs: &[String]) -> Result<Vec<PolicyCount>, String> {
+    let entries = parse(input).map_err(|e| e.to_string())?;
+    let registry = PolicyRegistry::default();
+
+    specs
+        .iter()
+        .map(|spec| {
+            let valid = count_valid(&entries, &*registry.build(spec)?);
+            Ok(PolicyCount {
+                policy: spec.clone(),
+                valid,
+                invalid: entries.len() - valid,
+            })
+        })
+        .collect()
+}
+
+pub fn format_counts(counts: &[PolicyCount], format: Format) -> String {
+    match format {
+        Format::Human => {
+            let width = counts
+                .iter()
+                .map(|c| c.policy.chars().count())
+                .chain(Some("policy".len()))
+                .max()
+                .unwrap_or(0);
+            let mut table = format!("{:<w$}  valid  invalid\n", "policy", w = width);
+            for c in counts {
+                table += &format!(
+                    "{:<w$}  {:>5}  {:>7}\n",
+                    c.policy,
+                    c.valid,
+                    c.invalid,
+                    w = width
+                );
+            }
+            table
+        }
+        Format::Json => {
+            let objects = counts
+                .iter()
+                .map(|c| {
+                    format!(
+                        "  {{\"policy\": {}, \"valid\": {}, \"invalid\": {}}}",
+                        json_string(&c.policy),
+                        c.valid,
+                        c.invalid
+                    )
+                })
+                .collect::<Vec<_>>();
+            json_array(&objects)
+        }
+        Format::Csv => {
+            let mut csv = String::from("policy,valid,invalid\n");
+            for c in counts {
+                csv += &format!("{},{},{}\n", csv_field(&c.policy), c.valid, c.invalid);
+            }
+            csv
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sled_rental_tests() {
+        assert!(SledRental.is_valid(&parse_line("1-3 a: abcde").unwrap()));
+        assert!(!SledRental.is_valid(&parse_line("1-3 b: cdefg").unwrap()));
+        assert!(SledRental.is_valid(&parse_line("2-9 c: ccccccccc").unwrap()));
+    }
+
+    #[test]
+    fn toboggan_tests() {
+        let toboggan = Toboggan::default();
+        assert!(toboggan.is_valid(&parse_line("1-3 a: abcde").unwrap()));
+        assert!(!toboggan.is_valid(&parse_line("1-3 b: cdefg").unwrap()));
+        assert!(!toboggan.is_valid(&parse_line("2-9 c: ccccccccc").unwrap()));
+
+        // Past the end, a position does not match.
+        assert!(toboggan.is_valid(&parse_line("2-7 a: bab").unwrap()));
+        assert!(!toboggan.is_valid(&parse_line("5-7 a: bab").unwrap()));
+
+        let entry = parse_line("2-4 a: éab").unwrap();
+        assert!(toboggan.is_valid(&entry));
+        assert_eq!(
+            Toboggan(Positions::Bytes).check(&entry),
+            Err("neither position 2 nor 4 matches".to_string())
+        );
+        assert!(Toboggan(Positions::Bytes).is_valid(&parse_line("3-4 a: éab").unwrap()));
+    }
+
+    #[test]
+    fn registry_tests() {
+        let registry = PolicyRegistry::default();
+        let entry = parse_line("1-3 a: abccde").unwrap();
+        assert!(!registry.build("forbidden=b").unwrap().is_valid(&entry));
+        assert!(registry.build("forbidden=z").unwrap().is_valid(&entry));
+        assert!(registry.build("max-repeat=2").unwrap().is_valid(&entry));
+        assert!(!registry.build("max-repeat=1").unwrap().is_valid(&entry));
+        assert!(registry.build("toboggan=1").is_err());
+        assert!(registry.build("max-repeat").is_err());
+        assert!(registry.build("forbidden=ab").is_err());
+        assert!(registry.build("length=8").is_err());
+
+        let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+        let specs = ["sled-rental".to_string(), "max-repeat=3".to_string()];
+        let counts = policy_counts(input, &specs).unwrap();
+        assert_eq!(
+            format_counts(&counts, Format::Human),
+            "policy        valid  invalid
+sled-rental       2        1
+max-repeat=3      2        1
+"
+        );
+        assert_eq!(
+            format_counts(&counts, Format::Csv),
+            "policy,valid,invalid\nsled-rental,2,1\nmax-repeat=3,2,1\n"
+        );
+    }
+
+    #[test]
+    fn violations_tests() {
+        let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+        let entries = parse(input).unwrap();
+        let specs = ["sled-rental", "toboggan", "max-repeat=4"].map(String::from);
+        let violations = violations(&entries, &specs).unwrap();
+
+        assert_eq!(
+            format_violations(&violations, entries.len(), Format::Human),
+            "line 2: cdefg breaks sled-rental: char 'b' appears 0 times, allowed 1-3
+line 2: cdefg breaks toboggan: neither position 1 nor 3 matches
+line 3: ccccccccc breaks toboggan: positions 2 and 9 both match
+line 3: ccccccccc breaks max-repeat=4: char 'c' repeats 9 times in a row, allowed 4
+4 violations, 2 of 3 passwords invalid
+"
+        );
+        assert_eq!(
+            format_violations(&violations[..1], entries.len(), Format::Json),
+            "[
+  {\"line\": 2, \"password\": \"cdefg\", \"policy\": \"sled-rental\", \"reason\": \"char 'b' appears 0 times, allowed 1-3\"}
+]
+"
+        );
+        assert_eq!(
+            Forbidden('d').check(&entries[0]),
+            Err("char 'd' is forbidden, found at position 4".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse("1-3 a: abcde\n1-x b: cdefg").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+
+        let e = parse("1-3 a: abcde\n2 c: ccccccccc").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "2"));
+
+        let e = parse("1-3 a: abcde\n0-3 c: ccc").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "0-3"));
+
+        let e = parse("3-1 a: abcde").unwrap_err();
+        assert_eq!(
+            (e.text.as_str(), e.expected.as_str()),
+            ("3-1", "a range with its lower bound first like `1-3`")
+        );
+
+        let e = parse("1-3 a:").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (1, 7, "a password")
+        );
+
+        for (line, letter) in [("1-3 ab: x", "ab:"), ("1-3 a x", "a"), ("1-3 : x", ":")] {
+            let e = parse(line).unwrap_err();
+            assert_eq!((e.column, e.text.as_str()), (5, letter), "{}", line);
+            assert_eq!(e.expected, "a letter like `a:`");
+        }
+
+        let e = parse("1-3 a: abcde\n1-3 b: cdefg and more").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 14, "and"));
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::generate::{Options, Rng};
+
+/// A map `size` rows high (323 by default) and 31 squares wide, about one square in five being
+/// a tree. Both parts always have an answer.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    (0..options.size_or(323))
+        .map(|_| {
+            let row = (0..31)
+                .map(|_| if rng.chance(0.2) { '#' } else { '.' })
+                .collect::<String>();
+            row + "\n"
+        })
+        .collect()
+}
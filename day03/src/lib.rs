@@ -0,0 +1,695 @@
+//! Day 3: Toboggan Trajectory. Counting the trees met sliding down a map that repeats itself to the right.
+
+use aoc_common::cli::DayOptions;
+use aoc_common::day::{Day, Part};
+use aoc_common::error::ParseError;
+use aoc_common::grid::Grid;
+use aoc_common::num::Arithmetic;
+use aoc_common::output::{json_array, json_string, Format};
+use aoc_common::solution::Solution;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Grid<Square>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day03>(3, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// A square of the map, which repeats itself to the right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+/// The trees met going right 3, down 1.
+pub fn part_one(input: &Grid<Square>) -> usize {
+    count_trees(input, 3, 1)
+}
+
+/// The product of the trees met on each of the five slopes.
+pub fn part_two(input: &Grid<Square>) -> usize {
+    tree_counts(input, &PUZZLE_SLOPES)
+        .iter()
+        .map(|&(_, trees)| trees)
+        .product()
+}
+
+/// The five slopes of part two.
+pub const PUZZLE_SLOPES: [Slope; 5] = [
+    Slope { dx: 1, dy: 1 },
+    Slope { dx: 3, dy: 1 },
+    Slope { dx: 5, dy: 1 },
+    Slope { dx: 7, dy: 1 },
+    Slope { dx: 1, dy: 2 },
+];
+
+/// Going right `dx`, down `dy` at every step, like `3,1`. It always goes down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Slope {
+    pub dx: usize,
+    pub dy: usize,
+}
+
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid slope: {:?}, expected right,down like 3,1", s);
+        let (dx, dy) = s.split_once(',').ok_or_else(invalid)?;
+
+        match (dx.trim().parse(), dy.trim().parse()) {
+            (Ok(dx), Ok(dy)) if dy > 0 => Ok(Slope { dx, dy }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.dx, self.dy)
+    }
+}
+
+/// The trees met on each of `slopes`.
+pub fn tree_counts(input: &Grid<Square>, slopes: &[Slope]) -> Vec<(Slope, usize)> {
+    slopes
+        .iter()
+        .map(|&slope| (slope, count_trees(input, slope.dx, slope.dy)))
+        .collect()
+}
+
+/// Every slope going right and down at most `bound` squares a step.
+pub fn slopes_up_to(bound: usize) -> Vec<Slope> {
+    (1..=bound)
+        .flat_map(|dy| (0..=bound).map(move |dx| Slope { dx, dy }))
+        .collect()
+}
+
+/// Which slopes rank first.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Order {
+    #[default]
+    FewestTrees,
+    MostTrees,
+}
+
+impl FromStr for Order {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fewest" => Ok(Order::FewestTrees),
+            "most" => Ok(Order::MostTrees),
+            o => Err(format!("Unknown order: {:?}, expected fewest or most", o)),
+        }
+    }
+}
+
+/// The trees met on every slope, the best slope first, ties broken by the slope.
+pub fn rank(mut counts: Vec<(Slope, usize)>, order: Order) -> Vec<(Slope, usize)> {
+    match order {
+        Order::FewestTrees => counts.sort_by_key(|&(slope, trees)| (trees, slope)),
+        Order::MostTrees => counts.sort_by_key(|&(slope, trees)| (Reverse(trees), slope)),
+    }
+    counts
+}
+
+pub fn format_ranking(ranking: &[(Slope, usize)], format: Format) -> String {
+    match format {
+        Format::Human => {
+            let mut table = String::from("rank  right  down  trees\n");
+            for (i, (slope, trees)) in ranking.iter().enumerate() {
+                table += &format!(
+                    "{:>4}  {:>5}  {:>4}  {:>5}\n",
+                    i + 1,
+                    slope.dx,
+                    slope.dy,
+                    trees
+                );
+            }
+            table
+        }
+        Format::Json => {
+            let objects = ranking
+                .iter()
+                .enumerate()
+                .map(|(i, (slope, trees))| {
+                    format!(
+                        "  {{\"rank\": {}, \"slope\": {}, \"right\": {}, \"down\": {}, \"trees\": {}}}",
+                        i + 1,
+                        json_string(&slope.to_string()),
+                        slope.dx,
+                        slope.dy,
+                        trees
+                    )
+                })
+                .collect::<Vec<_>>();
+            json_array(&objects)
+        }
+        Format::Csv => {
+            let mut csv = String::from("rank,right,down,trees\n");
+            for (i, (slope, trees)) in ranking.iter().enumerate() {
+                csv += &format!("{},{},{},{}\n", i + 1, slope.dx, slope.dy, trees);
+            }
+            csv
+        }
+    }
+}
+
+/// A step of the path planner: `dx` squares right, negative for left, and `dy` down, like
+/// `-1,1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub dx: isize,
+    pub dy: usize,
+}
+
+impl FromStr for Move {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid move: {:?}, expected right,down like -1,1", s);
+        let (dx, dy) = s.split_once(',').ok_or_else(invalid)?;
+
+        match (dx.trim().parse(), dy.trim().parse()) {
+            (Ok(dx), Ok(dy)) if (dx, dy) != (0, 0) => Ok(Move { dx, dy }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// One square down, and up to `lateral` squares left or right at the same time.
+pub fn down_moves(lateral: usize) -> Vec<Move> {
+    let lateral = lateral as isize;
+    (-lateral..=lateral).map(|dx| Move { dx, dy: 1 }).collect()
+}
+
+/// A way down the map and the trees met on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub trees: usize,
+    /// The squares visited from the top left corner, columns counting on the repeated map and
+    /// going negative left of the start.
+    pub path: Vec<(isize, usize)>,
+}
+
+/// The route from the top left corner to past the bottom of the map meeting the fewest trees,
+/// None when `moves` never get there. An error for a move wider than the map, which would jump
+/// over whole copies of it.
+///
+/// The map repeating itself, squares are only told apart by their column modulo the width:
+/// Dijkstra's algorithm on those, a square costing 1 for a tree.
+pub fn least_trees(map: &Grid<Square>, moves: &[Move]) -> Result<Option<Route>, String> {
+    let (width, height) = (map.width(), map.height());
+    if let Some(m) = moves.iter().find(|m| m.dx.unsigned_abs() > width) {
+        return Err(format!("The move {},{} is wider than the map", m.dx, m.dy));
+    }
+    if width == 0 || height == 0 {
+        return Ok(None);
+    }
+    let cost = |x: usize, y: usize| (map[(x, y)] == Square::Tree) as usize;
+    let lowest = match moves.iter().map(|m| m.dy).max() {
+        Some(lowest) => lowest,
+        None => return Ok(None),
+    };
+
+    let mut trees = Grid::filled(width, height, usize::MAX);
+    // The square and the move each square was best reached from.
+    let mut from = Grid::filled(width, height, None);
+    let mut queue = BinaryHeap::new();
+    trees[(0, 0)] = cost(0, 0);
+    queue.push(Reverse((cost(0, 0), 0, 0)));
+
+    while let Some(Reverse((t, y, x))) = queue.pop() {
+        if t > trees[(x, y)] {
+            continue;
+        }
+        // Dijkstra reaching the squares by increasing trees, the first one able to leave the
+        // map is the end of the best route.
+        if lowest >= height - y {
+            return Ok(Some(Route {
+                trees: t,
+                path: unwind(&from, (x, y), width),
+            }));
+        }
+
+        for (i, m) in moves.iter().enumerate() {
+            let ny = match y.checked_add(m.dy) {
+                Some(ny) if ny < height => ny,
+                _ => continue,
+            };
+            let nx = (x + m.dx.rem_euclid(width as isize) as usize) % width;
+            let nt = t + cost(nx, ny);
+            if nt < trees[(nx, ny)] {
+                trees[(nx, ny)] = nt;
+                from[(nx, ny)] = Some(((x, y), moves[i]));
+                queue.push(Reverse((nt, ny, nx)));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// The path to `end` from the start, replaying the moves to place it on the repeated map. Moves
+/// no wider than the map keep it within a map's width per row of the start.
+fn unwind(
+    from: &Grid<Option<((usize, usize), Move)>>,
+    end: (usize, usize),
+    width: usize,
+) -> Vec<(isize, usize)> {
+    let mut moves = Vec::new();
+    let mut square = end;
+    while let Some((previous, m)) = from[square] {
+        moves.push(m);
+        square = previous;
+    }
+
+    let mut path = vec![(0isize, 0)];
+    for m in moves.iter().rev() {
+        let &(x, y) = path.last().expect("The path starts at the top left corner");
+        path.push((x + m.dx, y + m.dy));
+    }
+    debug_assert_eq!(
+        path.last()
+            .map(|&(x, y)| (x.rem_euclid(width as isize) as usize, y)),
+        Some(end)
+    );
+    path
+}
+
+/// The map with the `visited` squares marked `O` when open and `X` on a tree, repeated to the
+/// left and right as far as they go.
+pub fn render(map: &Grid<Square>, visited: &[(isize, usize)]) -> String {
+    let width = map.width() as isize;
+    let tiles = |x: isize| x.div_euclid(width.max(1));
+    let left = visited
+        .iter()
+        .map(|&(x, _)| tiles(x))
+        .min()
+        .unwrap_or(0)
+        .min(0)
+        * width;
+    let right = (visited
+        .iter()
+        .map(|&(x, _)| tiles(x))
+        .max()
+        .unwrap_or(0)
+        .max(0)
+        + 1)
+        * width;
+    let visited = visited.iter().copied().collect::<HashSet<_>>();
+
+    (0..map.height())
+        .map(|y| {
+            (left..right)
+                .map(
+                    |x| match (map.get_wrapping(x, y as isize), visited.contains(&(x, y))) {
+                        (Square::Open, false) => '.',
+                        (Square::Tree, false) => '#',
+                        (Square::Open, true) => 'O',
+                        (Square::Tree, true) => 'X',
+                    },
+                )
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+pub fn format_route(map: &Grid<Square>, route: &Route, format: Format) -> String {
+    match format {
+        Format::Human => format!("{}{} trees\n", render(map, &route.path), route.trees),
+        Format::Json => {
+            let path = route
+                .path
+                .iter()
+                .map(|(x, y)| format!("[{}, {}]", x, y))
+                .collect::<Vec<_>>();
+            format!(
+                "{{\"trees\": {}, \"path\": [{}]}}\n",
+                route.trees,
+                path.join(", ")
+            )
+        }
+        Format::Csv => {
+            let mut csv = String::from("step,x,y,tree\n");
+            for (i, &(x, y)) in route.path.iter().enumerate() {
+                let tree = *map.get_wrapping(x, y as isize) == Square::Tree;
+                csv += &format!("{},{},{},{}\n", i, x, y, tree);
+            }
+            csv
+        }
+    }
+}
+
+/// The binary's own options: slopes to rank or a route to plan rather than solving the
+/// puzzle.
+#[derive(Debug, Default, PartialEq)]
+pub struct MapOptions {
+    pub slopes: Vec<Slope>,
+    /// Ranks every slope up to this many squares right and down as well.
+    pub search: Option<usize>,
+    pub order: Order,
+    /// Whether to plan the route meeting the fewest trees.
+    pub plan: bool,
+    /// The moves of the route, one down with one to the left or right by default.
+    pub moves: Vec<Move>,
+    /// The slope to draw on the map.
+    pub render: Option<Slope>,
+    /// Where to write the drawn route rather than printing it.
+    pub output: Option<PathBuf>,
+}
+
+impl DayOptions for MapOptions {
+    const USAGE: &'static str = "
+
+--slope RIGHT,DOWN, repeatable, ranks the slopes by the trees met on each instead.
+--search BOUND ranks every slope going at most BOUND squares right and down as well.
+--order fewest|most ranks the slopes meeting the fewest trees first (the default), or the
+most.
+
+--plan draws the route down the map meeting the fewest trees instead, made of the moves given
+with --move RIGHT,DOWN, repeatable and at most the map's width sideways, or of --lateral N:
+one square down and up to N left or right, 1 by default.
+
+--render RIGHT,DOWN draws the squares visited along the slope instead, O when open and X on a
+tree.
+--output PATH writes the ranking, or the route drawn by --plan or --render, to PATH rather
+than printing it.";
+
+    fn parse_option(
+        &mut self,
+        arg: &str,
+        value: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--slope" | "-s" => self.slopes.push(value()?.parse()?),
+            "--search" => {
+                let bound = value()?;
+                let bound = bound
+                    .parse()
+                    .map_err(|_| format!("Invalid bound: {:?}", bound))?;
+                self.search = Some(bound);
+            }
+            "--order" => self.order = value()?.parse()?,
+            "--plan" => self.plan = true,
+            "--move" | "-m" => self.moves.push(value()?.parse()?),
+            "--lateral" => {
+                let lateral = value()?;
+                let lateral = lateral
+                    .parse()
+                    .map_err(|_| format!("Invalid lateral moves: {:?}", lateral))?;
+                self.moves.extend(down_moves(lateral));
+            }
+            "--render" => self.render = Some(value()?.parse()?),
+            "--output" | "-o" => self.output = Some(PathBuf::from(value()?)),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn solve(&self, day: &Day, _: Arithmetic, part: Part, input: &str) -> Result<String, String> {
+        day.solve(part, input)
+    }
+
+    fn report(&self, input: &str, format: Format) -> Option<Result<String, String>> {
+        let ranks = !self.slopes.is_empty() || self.search.is_some();
+        let draws = self.plan || self.render.is_some();
+        let conflict = if self.plan && self.render.is_some() {
+            Some("Cannot both --plan and --render a route")
+        } else if ranks && draws {
+            Some("Cannot both rank slopes and draw a route")
+        } else if !self.moves.is_empty() && !self.plan {
+            Some("--move and --lateral are the moves of --plan")
+        } else if self.output.is_some() && !ranks && !draws {
+            Some("--output writes a ranking or a route, but none was asked for")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            return Some(Err(conflict.to_string()));
+        }
+
+        if draws {
+            return Some(parse(input).map_err(|e| e.to_string()).and_then(|map| {
+                let route = match self.render {
+                    Some(slope) => Some(slope_route(&map, slope)),
+                    None if self.moves.is_empty() => least_trees(&map, &down_moves(1))?,
+                    None => least_trees(&map, &self.moves)?,
+                }
+                .ok_or("No route reaches the bottom")?;
+                self.write(format_route(&map, &route, format))
+            }));
+        }
+        if !ranks {
+            return None;
+        }
+
+        let mut slopes = self.slopes.clone();
+        slopes.extend(self.search.map(slopes_up_to).unwrap_or_default());
+        slopes.sort();
+        slopes.dedup();
+
+        let report = parse(input).map_err(|e| e.to_string()).and_then(|map| {
+            let ranking = rank(tree_counts(&map, &slopes), self.order);
+            self.write(format_ranking(&ranking, format))
+        });
+        Some(report)
+    }
+}
+
+impl MapOptions {
+    /// The report to print, nothing once written to the output file.
+    fn write(&self, report: String) -> Result<String, String> {
+        match &self.output {
+            Some(path) => fs::write(path, report)
+                .map(|_| String::new())
+                .map_err(|e| format!("Cannot write {}: {}", path.display(), e)),
+            None => Ok(report),
+        }
+    }
+}
+
+/// A map of open squares `.` and trees `#`.
+pub fn parse(input: &str) -> Result<Grid<Square>, ParseError> {
+    let square = |c| match c {
+        '.' => Some(Square::Open),
+        '#' => Some(Square::Tree),
+        _ => None,
+    };
+    Grid::parse(input, square, "`.` or `#`")
+}
+
+/// The trees met from the top left corner going right `dx`, down `dy` until the bottom.
+pub fn count_trees(input: &Grid<Square>, dx: usize, dy: usize) -> usize {
+    slope_path(input, dx, dy)
+        .filter(|&(x, y)| *input.get_wrapping(x as isize, y as isize) == Square::Tree)
+        .count()
+}
+
+/// The squares visited going `dx` right and `dy` down from the top left corner, columns counting
+/// on the repeated map.
+pub fn slope_path(
+    input: &Grid<Square>,
+    dx: usize,
+    dy: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    (0..input.height())
+        .step_by(dy)
+        .enumerate()
+        .map(move |(i, y)| (i * dx, y))
+}
+
+/// The way down the map along `slope`.
+pub fn slope_route(map: &Grid<Square>, slope: Slope) -> Route {
+    Route {
+        trees: count_trees(map, slope.dx, slope.dy),
+        path: slope_path(map, slope.dx, slope.dy)
+            .map(|(x, y)| (x as isize, y))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const MAP: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn rank_test() {
+        let map = parse(MAP).unwrap();
+        assert_eq!(part_two(&map), 336);
+
+        let slopes = ["3,1", "1,2", "7,1"].map(|s| s.parse::<Slope>().unwrap());
+        let counts = tree_counts(&map, &slopes);
+        assert_eq!(
+            format_ranking(&rank(counts.clone(), Order::FewestTrees), Format::Human),
+            "rank  right  down  trees
+   1      1     2      2
+   2      7     1      4
+   3      3     1      7
+"
+        );
+        assert_eq!(
+            rank(counts, Order::MostTrees)[0],
+            (Slope { dx: 3, dy: 1 }, 7)
+        );
+
+        let ranking = rank(tree_counts(&map, &slopes_up_to(3)), Order::FewestTrees);
+        assert_eq!(ranking.len(), 12);
+        assert!(ranking.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        assert!("3,0".parse::<Slope>().is_err());
+        assert!("3".parse::<Slope>().is_err());
+    }
+
+    #[test]
+    fn least_trees_test() {
+        let map = parse(".#.\n##.\n#.#").unwrap();
+        let route = least_trees(&map, &down_moves(1)).unwrap().unwrap();
+        assert_eq!(
+            route,
+            Route {
+                trees: 0,
+                path: vec![(0, 0), (-1, 1), (-2, 2)]
+            }
+        );
+        assert_eq!(render(&map, &route.path), ".#.O#.\n##O##.\n#O##.#\n");
+
+        let map = parse(MAP).unwrap();
+        assert_eq!(
+            least_trees(&map, &down_moves(0)).map(|r| r.map(|r| r.trees)),
+            Ok(Some(count_trees(&map, 0, 1)))
+        );
+        let moves = ["3,1", "1,2"].map(|m| m.parse::<Move>().unwrap());
+        let route = least_trees(&map, &moves).unwrap().unwrap();
+        assert!(route.trees <= count_trees(&map, 1, 2).min(count_trees(&map, 3, 1)));
+        assert_eq!(route.path.len(), 7);
+
+        assert_eq!(least_trees(&map, &["1,0".parse().unwrap()]), Ok(None));
+        let far = [Move {
+            dx: isize::MAX,
+            dy: 1,
+        }];
+        assert_eq!(
+            least_trees(&map, &far),
+            Err(format!("The move {},1 is wider than the map", isize::MAX))
+        );
+        assert!(least_trees(&map, &["-12,1".parse().unwrap()]).is_err());
+        let wrapping = ["-11,1".parse::<Move>().unwrap()];
+        assert_eq!(
+            least_trees(&map, &wrapping).map(|r| r.map(|r| r.trees)),
+            Ok(Some(count_trees(&map, 0, 1)))
+        );
+        assert!("0,0".parse::<Move>().is_err());
+    }
+
+    #[test]
+    fn slope_route_test() {
+        let map = parse(MAP).unwrap();
+        let route = slope_route(&map, "3,1".parse().unwrap());
+        assert_eq!(route.trees, 7);
+        assert_eq!(route.path[..3], [(0, 0), (3, 1), (6, 2)]);
+        assert_eq!(
+            render(&map, &route.path)
+                .lines()
+                .take(3)
+                .collect::<Vec<_>>(),
+            [
+                "O.##.........##.........##.......",
+                "#..O#...#..#...#...#..#...#...#..",
+                ".#....X..#..#....#..#..#....#..#.",
+            ]
+        );
+        assert_eq!(
+            slope_path(&map, 1, 2).collect::<Vec<_>>(),
+            [(0, 0), (1, 2), (2, 4), (3, 6), (4, 8), (5, 10)]
+        );
+    }
+
+    #[test]
+    fn report_test() {
+        let options = MapOptions {
+            slopes: vec![Slope { dx: 3, dy: 1 }],
+            plan: true,
+            ..MapOptions::default()
+        };
+        assert_eq!(
+            options.report(MAP, Format::Csv),
+            Some(Err("Cannot both rank slopes and draw a route".to_string()))
+        );
+
+        let output = std::env::temp_dir().join("aoc-day03-ranking.csv");
+        let options = MapOptions {
+            plan: false,
+            output: Some(output.clone()),
+            ..options
+        };
+        assert_eq!(options.report(MAP, Format::Csv), Some(Ok(String::new())));
+        assert_eq!(
+            std::fs::read_to_string(output).unwrap(),
+            "rank,right,down,trees\n1,3,1,7\n"
+        );
+
+        assert_eq!(MapOptions::default().report(MAP, Format::Csv), None);
+    }
+
+    #[test]
+    fn count_trees_test() {
+        let input = parse(MAP).unwrap();
+        assert_eq!(count_trees(&input, 1, 1), 2);
+        assert_eq!(count_trees(&input, 3, 1), 7);
+        assert_eq!(count_trees(&input, 5, 1), 3);
+        assert_eq!(count_trees(&input, 7, 1), 4);
+        assert_eq!(count_trees(&input, 1, 2), 2);
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let e = parse("..#\n.x.").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+
+        let e = parse("..#\n.#").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, ".#"));
+    }
+}
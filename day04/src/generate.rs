@@ -0,0 +1,70 @@
+use aoc_common::generate::{Options, Rng};
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// `size` passports (250 by default), with missing fields and invalid values here and there.
+/// When solvable, the first passport is valid for both parts.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let passports = (0..options.size_or(250))
+        .map(|i| {
+            let always_valid = options.solvable && i == 0;
+            let mut fields = Vec::new();
+            for &key in &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+                if always_valid || rng.chance(if key == "cid" { 0.5 } else { 0.9 }) {
+                    let valid = always_valid || rng.chance(0.8);
+                    fields.push(format!("{}:{}", key, value(rng, key, valid)));
+                }
+            }
+            rng.shuffle(&mut fields);
+
+            fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let separator = if i == 0 {
+                        ""
+                    } else if rng.chance(0.3) {
+                        "\n"
+                    } else {
+                        " "
+                    };
+                    format!("{}{}", separator, field)
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    passports.join("\n\n") + "\n"
+}
+
+fn value(rng: &mut Rng, key: &str, valid: bool) -> String {
+    let year = |rng: &mut Rng, first: u64, last: u64| {
+        if valid {
+            rng.range(first..=last).to_string()
+        } else {
+            rng.range(1900..=2040).to_string()
+        }
+    };
+
+    match key {
+        "byr" => year(rng, 1920, 2002),
+        "iyr" => year(rng, 2010, 2020),
+        "eyr" => year(rng, 2020, 2030),
+        "hgt" => match (valid, rng.chance(0.5)) {
+            (true, true) => format!("{}cm", rng.range(150..=193)),
+            (true, false) => format!("{}in", rng.range(59..=76)),
+            (false, true) => format!("{}cm", rng.range(50..=250)),
+            (false, false) => rng.range(50..=250).to_string(),
+        },
+        "hcl" if valid => format!("#{}", rng.string(b"0123456789abcdef", 6)),
+        "hcl" => rng.string(b"0123456789abcdefz", 6),
+        "ecl" if valid => rng.choose(&EYE_COLORS).to_string(),
+        "ecl" => rng.string(b"abcdefghijklmnopqrstuvwxyz", 3),
+        "pid" if valid => rng.string(b"0123456789", 9),
+        "pid" => {
+            let len = rng.range(5..=12) as usize;
+            rng.string(b"0123456789", len)
+        }
+        _ => rng.range(1..=350).to_string(),
+    }
+}
@@ -0,0 +1,292 @@
+//! Day 4: Passport Processing. Telling valid passports apart, first by their fields then by their values.
+
+use aoc_common::cli::DayOptions;
+use aoc_common::day::{Day, Part};
+use aoc_common::error::ParseError;
+use aoc_common::num::Arithmetic;
+use aoc_common::parser::{
+    and_then, map, parse_all, parse_blocks, separated, take_while1, Failure, Parsed,
+};
+use aoc_common::solution::Solution;
+use schema::{Schema, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+pub mod generate;
+pub mod pattern;
+pub mod schema;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day04;
+
+impl Solution for Day04 {
+    // The puzzle's schema is read once while parsing rather than by each part. One that cannot
+    // be read fails both parts, the input itself being fine.
+    type Input<'a> = (Vec<Fields<'a>>, Result<Schema, String>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((parse(input)?, Schema::puzzle()))
+    }
+
+    fn part_one((passports, schema): &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(passports, schema.as_ref()?))
+    }
+
+    fn part_two((passports, schema): &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(passports, schema.as_ref()?))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day04>(4, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// How many passports have every required field.
+pub fn part_one(passports: &[Fields], schema: &Schema) -> usize {
+    passports
+        .iter()
+        .filter(|p| PassportDto::new(p, schema).is_ok())
+        .count()
+}
+
+/// How many passports have every required field with a valid value.
+pub fn part_two(passports: &[Fields], schema: &Schema) -> usize {
+    passports
+        .iter()
+        .filter(|p| Passport::new(p, schema).is_ok())
+        .count()
+}
+
+/// The `key:value` fields of one passport, e.g. `byr:1937`.
+pub type Fields<'a> = HashMap<&'a str, &'a str>;
+
+/// Passports separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Fields<'_>>, ParseError> {
+    parse_blocks(input, |p| match p.trim() {
+        "" => Ok(Fields::new()),
+        p => parse_all(p, fields),
+    })
+}
+
+/// Fields separated by spaces or line breaks.
+fn fields(s: &str) -> Parsed<'_, Fields<'_>> {
+    let field = and_then(take_while1(|c: char| !c.is_whitespace(), "a field"), |kv| {
+        kv.split_once(':')
+            .ok_or_else(|| Failure::new(kv, "a field like `byr:1937`"))
+    });
+
+    map(
+        separated(field, take_while1(char::is_whitespace, "a space")),
+        |fields| fields.into_iter().collect(),
+    )(s)
+}
+
+/// A passport with every required field of the schema, whatever their values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassportDto {
+    /// The values of the fields the schema knows, by key.
+    pub fields: BTreeMap<String, String>,
+}
+
+impl PassportDto {
+    pub fn new(fields: &Fields, schema: &Schema) -> Result<Self, String> {
+        let mut known = BTreeMap::new();
+        for field in &schema.fields {
+            match fields.get(field.key.as_str()) {
+                Some(value) => {
+                    known.insert(field.key.clone(), value.to_string());
+                }
+                None if field.required => return Err(field.missing_message()),
+                None => {}
+            }
+        }
+
+        Ok(PassportDto { fields: known })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(String::as_str)
+    }
+}
+
+/// A passport whose fields all have valid values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passport {
+    /// The values of the fields the schema knows, by key, as their rule reads them.
+    pub fields: BTreeMap<String, Value>,
+}
+
+impl Passport {
+    /// The passport when it has every required field, and every field of the schema it has
+    /// is valid, otherwise the message of the first field failing in the schema's order.
+    pub fn new(fields: &Fields, schema: &Schema) -> Result<Self, String> {
+        let p = PassportDto::new(fields, schema)?;
+
+        let mut values = BTreeMap::new();
+        for field in &schema.fields {
+            if let Some(value) = p.get(&field.key) {
+                values.insert(field.key.clone(), field.check(value)?);
+            }
+        }
+
+        Ok(Passport { fields: values })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
+}
+
+/// The binary's own options: the schema to check the passports with, the puzzle's by default.
+#[derive(Debug, Default, PartialEq)]
+pub struct SchemaOptions {
+    pub schema: Option<PathBuf>,
+}
+
+impl DayOptions for SchemaOptions {
+    const USAGE: &'static str = "
+
+--schema PATH checks the passports against the fields and rules of the schema at PATH rather
+than the puzzle's, day04/schema.toml, read at each run too. Its `regex` rules are a restricted
+subset of regular expressions, without groups nor alternatives like `(cm|in)`.";
+
+    fn parse_option(
+        &mut self,
+        arg: &str,
+        value: &mut dyn FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--schema" => self.schema = Some(PathBuf::from(value()?)),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn solve(&self, day: &Day, _: Arithmetic, part: Part, input: &str) -> Result<String, String> {
+        let path = match &self.schema {
+            Some(path) => path,
+            None => return day.solve(part, input),
+        };
+
+        let schema = Schema::load(path)?;
+        let passports = parse(input).map_err(|e| e.to_string())?;
+        let count = match part {
+            Part::One => part_one(&passports, &schema),
+            Part::Two => part_two(&passports, &schema),
+        };
+        Ok(count.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+        assert_eq!(
+            part_one(&parse(input).unwrap(), &Schema::puzzle().unwrap()),
+            2
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+        assert_eq!(
+            part_two(&parse(input).unwrap(), &Schema::puzzle().unwrap()),
+            0
+        );
+
+        let input = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+        assert_eq!(
+            part_two(&parse(input).unwrap(), &Schema::puzzle().unwrap()),
+            4
+        );
+    }
+
+    #[test]
+    fn passport_test() {
+        let passports = parse("byr:1937 hgt:183cm zzz:1\n\nhgt:183 byr:1937").unwrap();
+        let schema = "[hgt]\nrule = \"units cm 150-193\"\nmissing = \"No height\"\n\
+                      [byr]\nrequired = false\nrule = \"int 1920-2002\""
+            .parse::<Schema>()
+            .unwrap();
+
+        let passport = Passport::new(&passports[0], &schema).unwrap();
+        assert_eq!(
+            passport.get("hgt"),
+            Some(&Value::Measure(183, "cm".to_string()))
+        );
+        assert_eq!(passport.get("byr"), Some(&Value::Int(1937)));
+        assert_eq!(passport.get("zzz"), None);
+        assert_eq!(
+            Passport::new(&passports[1], &schema),
+            Err(
+                "Invalid hgt (hgt): expected a measure from 150 to 193 cm, found \"183\""
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            PassportDto::new(&Fields::new(), &schema),
+            Err("No height".to_string())
+        );
+        assert_eq!(part_one(&passports, &schema), 2);
+        assert_eq!(part_two(&passports, &schema), 1);
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let input = "ecl:gry pid:860033327
+
+iyr:2013 ecl:amb
+hcl:#cfa07d byr1929";
+        let e = parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 13, "byr1929"));
+
+        let e = parse("garbage").unwrap_err();
+        assert_eq!(e.expected, "a field like `byr:1937`");
+    }
+}
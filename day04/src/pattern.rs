@@ -0,0 +1,272 @@
+//! The small part of regular expressions the passport rules need, matching whole values.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// A restricted, glob-like subset of regular expressions, without groups nor alternatives:
+/// characters, `.`, `\d`, `\w`, `\s`, escaped punctuation like `\.` and classes like `[0-9a-f]`,
+/// `[^#]`, `[\d_]` or `[!-\]]`, each optionally repeated with `?`, `*`, `+`, `{n}`, `{n,}` or `{n,m}`. It
+/// always matches the whole value, `^` and `$` being allowed at the ends for clarity.
+///
+/// Matching takes at most a step per item, position and length of repetition, however the
+/// pattern came to be written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    source: String,
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Item {
+    atom: Atom,
+    min: usize,
+    max: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Atom {
+    Char(char),
+    Any,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Atom {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Atom::Char(expected) => c == *expected,
+            Atom::Any => true,
+            Atom::Class { negated, ranges } => {
+                ranges.iter().any(|&(low, high)| (low..=high).contains(&c)) != *negated
+            }
+        }
+    }
+}
+
+impl Pattern {
+    pub fn is_match(&self, s: &str) -> bool {
+        let chars = s.chars().collect::<Vec<_>>();
+        matches(&self.items, &chars, 0, 0, &mut HashSet::new())
+    }
+}
+
+/// Whether the items from the `i`th match all of `chars` from the `at`th, trying the longest
+/// repetitions first. The item and position pairs known to fail are not tried twice.
+fn matches(
+    items: &[Item],
+    chars: &[char],
+    i: usize,
+    at: usize,
+    failed: &mut HashSet<(usize, usize)>,
+) -> bool {
+    let item = match items.get(i) {
+        Some(item) => item,
+        None => return at == chars.len(),
+    };
+    if failed.contains(&(i, at)) {
+        return false;
+    }
+
+    let longest = chars[at..]
+        .iter()
+        .take(item.max.unwrap_or(chars.len()))
+        .take_while(|&&c| item.atom.matches(c))
+        .count();
+
+    let found = (item.min..=longest)
+        .rev()
+        .any(|n| matches(items, chars, i + 1, at + n, failed));
+    if !found {
+        failed.insert((i, at));
+    }
+    found
+}
+
+impl FromStr for Pattern {
+    /// Why the pattern is not supported.
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |why: &str| why.to_string();
+        let unclosed = || invalid("a `[` is never closed");
+        let body = s.strip_prefix('^').unwrap_or(s);
+        let body = match body.strip_suffix('$') {
+            Some(body) if !body.ends_with('\\') => body,
+            _ => body,
+        };
+
+        let mut items = Vec::new();
+        let mut chars = body.chars().peekable();
+        while let Some(c) = chars.next() {
+            let atom = match c {
+                '.' => Atom::Any,
+                '\\' => escaped(chars.next().ok_or_else(|| invalid("nothing to escape"))?)?,
+                '[' => {
+                    let negated = chars.next_if_eq(&'^').is_some();
+                    let mut ranges = Vec::new();
+                    loop {
+                        let low = match chars.next() {
+                            Some(']') if !ranges.is_empty() => break,
+                            Some('\\') => {
+                                let c = chars.next().ok_or_else(unclosed)?;
+                                if let Atom::Class { ranges: class, .. } = escaped(c)? {
+                                    ranges.extend(class);
+                                    continue;
+                                }
+                                c
+                            }
+                            Some(c) => c,
+                            None => return Err(unclosed()),
+                        };
+                        let high = match chars.next_if_eq(&'-') {
+                            Some(_) if chars.peek() != Some(&']') => match chars.next() {
+                                Some('\\') => match escaped(chars.next().ok_or_else(unclosed)?)? {
+                                    Atom::Char(c) => c,
+                                    _ => return Err(invalid("a range ends with a class")),
+                                },
+                                Some(c) => c,
+                                None => return Err(unclosed()),
+                            },
+                            Some(_) => {
+                                ranges.push(('-', '-'));
+                                low
+                            }
+                            None => low,
+                        };
+                        if high < low {
+                            return Err(invalid("a range goes backwards"));
+                        }
+                        ranges.push((low, high));
+                    }
+                    Atom::Class { negated, ranges }
+                }
+                '?' | '*' | '+' | '{' => return Err(invalid("a repetition repeats nothing")),
+                '(' | ')' | '|' => {
+                    return Err(invalid("groups and alternatives are not supported"))
+                }
+                c => Atom::Char(c),
+            };
+
+            let (min, max) = match chars.next_if(|c| "?*+{".contains(*c)) {
+                Some('?') => (0, Some(1)),
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some(_) => {
+                    let mut bounds = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => bounds.push(c),
+                            None => return Err(invalid("a `{` is never closed")),
+                        }
+                    }
+                    let number = |n: &str| {
+                        n.trim()
+                            .parse::<usize>()
+                            .map_err(|_| invalid("expected a repetition like `{6}` or `{2,4}`"))
+                    };
+                    match bounds.split_once(',') {
+                        None => (number(&bounds)?, Some(number(&bounds)?)),
+                        Some((min, max)) if max.trim().is_empty() => (number(min)?, None),
+                        Some((min, max)) => (number(min)?, Some(number(max)?)),
+                    }
+                }
+                None => (1, Some(1)),
+            };
+            if max.is_some_and(|max| max < min) {
+                return Err(invalid("a repetition has its bounds backwards"));
+            }
+
+            items.push(Item { atom, min, max });
+        }
+
+        Ok(Pattern {
+            source: s.to_string(),
+            items,
+        })
+    }
+}
+
+/// The atom of `\c`, an error for the letter and digit escapes other than `\d`, `\w` and `\s`.
+fn escaped(c: char) -> Result<Atom, String> {
+    let class = |ranges: &[(char, char)]| Atom::Class {
+        negated: false,
+        ranges: ranges.to_vec(),
+    };
+
+    match c {
+        'd' => Ok(class(&[('0', '9')])),
+        'w' => Ok(class(&[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')])),
+        's' => Ok(class(&[(' ', ' '), ('\t', '\r')])),
+        c if c.is_alphanumeric() => Err(format!("`\\{}` is not supported", c)),
+        c => Ok(Atom::Char(c)),
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn is_match_test() {
+        let color = "#[0-9a-f]{6}".parse::<Pattern>().unwrap();
+        assert!(color.is_match("#623a2f"));
+        assert!(!color.is_match("#623a2"));
+        assert!(!color.is_match("#623a2f0"));
+        assert!(!color.is_match("623a2f"));
+        assert!(!color.is_match("#623g2f"));
+
+        let height = r"^\d+(cm|in)?$".parse::<Pattern>();
+        assert!(height.is_err());
+        let height = r"^\d{2,3}[ci][mn]?.*$".parse::<Pattern>().unwrap();
+        assert!(height.is_match("183cm"));
+        assert!(height.is_match("59in"));
+        assert!(height.is_match("59i and more"));
+        assert!(!height.is_match("5in"));
+        assert!(!height.is_match("1830cm"));
+
+        let words = r"[^-]\w*-?[a-]+".parse::<Pattern>().unwrap();
+        assert!(words.is_match("ab_1-a-"));
+        assert!(!words.is_match("-ab"));
+        assert_eq!(words.to_string(), r"[^-]\w*-?[a-]+");
+
+        let class = r"[\d_\]]+".parse::<Pattern>().unwrap();
+        assert!(class.is_match("5_]"));
+        assert!(!class.is_match("d"));
+
+        let punctuation = r"[!-\]]\.".parse::<Pattern>().unwrap();
+        assert!(punctuation.is_match("].") && punctuation.is_match("#."));
+        assert!(!punctuation.is_match("^.") && !punctuation.is_match("]a"));
+
+        // Each item and position once, rather than every way to split the `a`s.
+        let stars = format!("{}b", "a*".repeat(30)).parse::<Pattern>().unwrap();
+        assert!(!stars.is_match(&"a".repeat(50)));
+        assert!(stars.is_match(&format!("{}b", "a".repeat(50))));
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        for pattern in ["+a", "a{2", "a{3,2}", "[a", "[z-a]", "a|b", "\\", "[a-\\"] {
+            assert!(pattern.parse::<Pattern>().is_err(), "{}", pattern);
+        }
+        for pattern in [
+            r"\D", r"\W", r"\S", r"\b", r"a\n", r"[\W]", r"[a-\d]", r"[\t]",
+        ] {
+            assert!(pattern.parse::<Pattern>().is_err(), "{}", pattern);
+        }
+        assert_eq!(
+            r"\D+".parse::<Pattern>(),
+            Err(r"`\D` is not supported".to_string())
+        );
+    }
+}
@@ -0,0 +1,402 @@
+//! The fields of a passport and the rules of their values, read from a schema file like
+//! `schema.toml`.
+
+use crate::pattern::Pattern;
+use aoc_common::answers::parse_value;
+use aoc_common::error::ParseError;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Once;
+
+/// Name of the file holding the rules of the puzzle, next to the day's `input.txt`.
+pub const SCHEMA_FILE_NAME: &str = "schema.toml";
+
+/// The rules of the puzzle as they were when built, for a binary run away from its sources.
+pub const PUZZLE_SCHEMA: &str = include_str!("../schema.toml");
+
+/// The fields of a passport, in the order they are checked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    /// Where the rules of the puzzle are read from.
+    pub fn puzzle_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(SCHEMA_FILE_NAME)
+    }
+
+    /// The rules of the puzzle, read from `schema.toml` so that editing it takes effect
+    /// without a rebuild. The copy built in only serves when the file is gone, which is said
+    /// once on stderr.
+    pub fn puzzle() -> Result<Schema, String> {
+        static FALLBACK: Once = Once::new();
+
+        let path = Schema::puzzle_path();
+        match fs::read_to_string(&path) {
+            Ok(text) => Schema::parse_file(&path, &text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                FALLBACK.call_once(|| {
+                    eprintln!(
+                        "{} not found, using the schema built into the binary",
+                        path.display()
+                    )
+                });
+                Ok(PUZZLE_SCHEMA
+                    .parse()
+                    .expect("The built-in puzzle schema should be valid"))
+            }
+            Err(e) => Err(format!("Cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Schema, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        Schema::parse_file(path, &text)
+    }
+
+    /// The schema in `text`, read from `path`, or its diagnostic under the path of the file.
+    fn parse_file(path: &Path, text: &str) -> Result<Schema, String> {
+        text.parse()
+            .map_err(|e: ParseError| format!("{}:\n{}", path.display(), e))
+    }
+}
+
+/// One field of the passport and the rule of its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub key: String,
+    /// How messages call the field, e.g. "Birth year".
+    pub name: String,
+    pub required: bool,
+    pub rule: Rule,
+    /// The message of a missing field, replacing the default one.
+    pub missing: Option<String>,
+    /// The message of an invalid value, replacing the default one.
+    pub message: Option<String>,
+}
+
+impl Field {
+    fn new(key: &str) -> Field {
+        Field {
+            key: key.to_string(),
+            name: key.to_string(),
+            required: true,
+            rule: Rule::Any,
+            missing: None,
+            message: None,
+        }
+    }
+
+    pub fn missing_message(&self) -> String {
+        self.missing
+            .clone()
+            .unwrap_or_else(|| format!("{} ({}) not found.", self.name, self.key))
+    }
+
+    /// The value checked against the rule, or the message saying why it is invalid.
+    pub fn check(&self, value: &str) -> Result<Value, String> {
+        self.rule.check(value).map_err(|expected| {
+            self.message.clone().unwrap_or_else(|| {
+                format!(
+                    "Invalid {} ({}): expected {}, found {:?}",
+                    self.name, self.key, expected, value
+                )
+            })
+        })
+    }
+}
+
+/// What a valid value looks like.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// Any value.
+    Any,
+    /// An integer within the range, like `int 1920-2002`.
+    Int(RangeInclusive<u64>),
+    /// An integer followed by a unit, within the range of the unit, like
+    /// `units cm 150-193, in 59-76`.
+    Units(Vec<(String, RangeInclusive<u64>)>),
+    /// A value matching the pattern, like `regex #[0-9a-f]{6}`.
+    Regex(Pattern),
+    /// One of the values, like `enum amb blu brn`.
+    Enum(Vec<String>),
+    /// Exactly this many digits, like `digits 9`.
+    Digits(usize),
+}
+
+/// A valid value, as its rule reads it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(u64),
+    /// The value and its unit, e.g. `(183, "cm")`.
+    Measure(u64, String),
+    Text(String),
+}
+
+impl Rule {
+    /// The value when valid, otherwise what was expected instead.
+    pub fn check(&self, value: &str) -> Result<Value, String> {
+        let within = |range: &RangeInclusive<u64>, n: &str| {
+            Some(n)
+                .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))?
+                .parse()
+                .ok()
+                .filter(|n| range.contains(n))
+        };
+
+        match self {
+            Rule::Any => Ok(Value::Text(value.to_string())),
+            Rule::Int(range) => within(range, value)
+                .map(Value::Int)
+                .ok_or_else(|| format!("an integer from {} to {}", range.start(), range.end())),
+            Rule::Units(units) => units
+                .iter()
+                .find_map(|(unit, range)| {
+                    let n = value.strip_suffix(unit.as_str())?;
+                    within(range, n).map(|n| Value::Measure(n, unit.clone()))
+                })
+                .ok_or_else(|| {
+                    let units = units
+                        .iter()
+                        .map(|(unit, range)| {
+                            format!("{} to {} {}", range.start(), range.end(), unit)
+                        })
+                        .collect::<Vec<_>>();
+                    format!("a measure from {}", units.join(" or "))
+                }),
+            Rule::Regex(pattern) => match pattern.is_match(value) {
+                true => Ok(Value::Text(value.to_string())),
+                false => Err(format!("a value matching `{}`", pattern)),
+            },
+            Rule::Enum(values) => match values.iter().any(|v| v == value) {
+                true => Ok(Value::Text(value.to_string())),
+                false => Err(format!("one of {}", values.join(", "))),
+            },
+            Rule::Digits(length) => {
+                match value.len() == *length && value.chars().all(|c| c.is_ascii_digit()) {
+                    true => Ok(Value::Text(value.to_string())),
+                    false => Err(format!("{} digits", length)),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, rest) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+        let rest = rest.trim();
+        let range = |r: &str| -> Result<RangeInclusive<u64>, String> {
+            let range = r
+                .split_once('-')
+                .and_then(|(min, max)| Some(min.trim().parse().ok()?..=max.trim().parse().ok()?))
+                .ok_or_else(|| "a range like `1920-2002`".to_string())?;
+            match range.is_empty() {
+                true => Err("a range with its smaller bound first like `1920-2002`".to_string()),
+                false => Ok(range),
+            }
+        };
+
+        match kind {
+            "any" if rest.is_empty() => Ok(Rule::Any),
+            "int" => Ok(Rule::Int(range(rest)?)),
+            "units" => rest
+                .split(',')
+                .map(|unit| {
+                    let (unit, r) = unit.trim().split_once(' ').unwrap_or_default();
+                    match range(r) {
+                        Ok(r) if !unit.is_empty() => Ok((unit.to_string(), r)),
+                        _ => Err("units like `units cm 150-193, in 59-76`".to_string()),
+                    }
+                })
+                .collect::<Result<_, _>>()
+                .map(Rule::Units),
+            "regex" => rest
+                .parse()
+                .map(Rule::Regex)
+                .map_err(|e| format!("a pattern it supports, but {}", e)),
+            "enum" if !rest.is_empty() => Ok(Rule::Enum(
+                rest.split_whitespace().map(str::to_string).collect(),
+            )),
+            "digits" => rest
+                .parse()
+                .map(Rule::Digits)
+                .map_err(|_| "a length like `digits 9`".to_string()),
+            _ => Err(
+                "a rule like `any`, `int 1920-2002`, `units cm 150-193, in 59-76`, \
+                 `regex #[0-9a-f]{6}`, `enum amb blu` or `digits 9`"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+/// Reads the subset of TOML a schema needs: a `[key]` section per field with `name`,
+/// `required`, `rule`, `missing` and `message` entries, blank lines and `#` comments.
+impl FromStr for Schema {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = Vec::<Field>::new();
+
+        for line in s.lines() {
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = content.strip_prefix('[') {
+                let key = section
+                    .split_once(']')
+                    .map(|(key, _)| key.trim())
+                    .filter(|key| !key.is_empty() && !key.contains(char::is_whitespace))
+                    .ok_or_else(|| ParseError::new(s, content, "a field like `[byr]`"))?;
+                if fields.iter().any(|f| f.key == key) {
+                    return Err(ParseError::new(s, key, "each field described only once"));
+                }
+                fields.push(Field::new(key));
+                continue;
+            }
+
+            let field = fields
+                .last_mut()
+                .ok_or_else(|| ParseError::new(s, content, "a field like `[byr]` first"))?;
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(s, content, "an entry like `rule = \"any\"`"))?;
+            let (key, value) = (key.trim(), value.trim());
+            let string = || parse_value(value).map_err(|e| e.within(s, value));
+            // What a string holds, to point at rather than its quotes.
+            let unquoted = value
+                .strip_prefix('"')
+                .and_then(|v| v.rsplit_once('"'))
+                .map_or(value, |(v, _)| v);
+
+            match key {
+                "name" => field.name = string()?,
+                "required" => {
+                    field.required = match value.split('#').next().unwrap_or_default().trim() {
+                        "true" => true,
+                        "false" => false,
+                        _ => return Err(ParseError::new(s, value, "`true` or `false`")),
+                    }
+                }
+                "rule" => {
+                    field.rule = string()?
+                        .parse()
+                        .map_err(|e| ParseError::new(s, unquoted, e))?
+                }
+                "missing" => field.missing = Some(string()?),
+                "message" => field.message = Some(string()?),
+                _ => {
+                    return Err(ParseError::new(
+                        s,
+                        key,
+                        "`name`, `required`, `rule`, `missing` or `message`",
+                    ))
+                }
+            }
+        }
+
+        Ok(Schema { fields })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn check_test() {
+        let schema = Schema::puzzle().unwrap();
+        assert_eq!(Ok(&schema), PUZZLE_SCHEMA.parse::<Schema>().as_ref());
+        let field = |key: &str| schema.fields.iter().find(|f| f.key == key).unwrap();
+
+        assert_eq!(field("byr").check("2002"), Ok(Value::Int(2002)));
+        assert_eq!(
+            field("byr").check("2003"),
+            Err(
+                "Invalid Birth year (byr): expected an integer from 1920 to 2002, found \"2003\""
+                    .to_string()
+            )
+        );
+        assert!(field("byr").check("+1937").is_err());
+        assert_eq!(
+            field("hgt").check("60in"),
+            Ok(Value::Measure(60, "in".to_string()))
+        );
+        assert!(field("hgt").check("190in").is_err());
+        assert!(field("hgt").check("190").is_err());
+        assert_eq!(
+            field("hcl").check("#123abz"),
+            Err("Hair color should be `#` then 6 hexadecimal digits".to_string())
+        );
+        assert!(field("ecl").check("wat").is_err());
+        assert_eq!(
+            field("pid").check("000000001"),
+            Ok(Value::Text("000000001".to_string()))
+        );
+        assert!(field("pid").check("0123456789").is_err());
+        assert!(!field("cid").required);
+        assert_eq!(
+            field("cid").missing_message(),
+            "Country id (cid) not found."
+        );
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let e = "rule = \"any\"".parse::<Schema>().unwrap_err();
+        assert_eq!(e.expected, "a field like `[byr]` first");
+
+        let e = "[byr]\nrule = \"int 1920\"".parse::<Schema>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 9, "int 1920"));
+        assert_eq!(e.expected, "a range like `1920-2002`");
+
+        let e = "[hcl]\nrule = \"regex (a|b)\""
+            .parse::<Schema>()
+            .unwrap_err();
+        assert_eq!(
+            e.expected,
+            "a pattern it supports, but groups and alternatives are not supported"
+        );
+
+        let e = "[byr]\nrequired = yes".parse::<Schema>().unwrap_err();
+        assert_eq!(e.text, "yes");
+
+        let e = "[byr]\n[byr]".parse::<Schema>().unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (2, "byr"));
+
+        let e = "[byr]\nrule = \"int 2002-1920\""
+            .parse::<Schema>()
+            .unwrap_err();
+        assert_eq!(
+            e.expected,
+            "a range with its smaller bound first like `1920-2002`"
+        );
+        assert!("units cm 193-150".parse::<Rule>().is_err());
+
+        let path = std::env::temp_dir().join("aoc-day04-schema.toml");
+        fs::write(&path, "[byr]\nrule = \"int 19\"\n").unwrap();
+        assert_eq!(
+            Schema::load(&path),
+            Err(format!(
+                "{}:
+error: expected a range like `1920-2002`, found \"int 19\"
+ --> line 2, column 9
+  |
+2 | rule = \"int 19\"
+  |         ^^^^^^",
+                path.display()
+            ))
+        );
+    }
+}
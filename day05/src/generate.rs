@@ -0,0 +1,38 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` distinct boarding passes (800 by default, at most 1022). When solvable, their seats
+/// are consecutive but for a single missing one, which is yours.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(800).min(1022) as u64;
+
+    let mut ids = if options.solvable {
+        let size = size.max(2);
+        // Seat 0 stays empty: the front row is not part of the plane.
+        let first = rng.range(1..=1023 - size);
+        let yours = rng.range(first + 1..=first + size - 1);
+        (first..=first + size).filter(|&id| id != yours).collect()
+    } else {
+        let mut ids = (0..1024).collect::<Vec<u64>>();
+        rng.shuffle(&mut ids);
+        ids.truncate(size as usize);
+        ids
+    };
+    rng.shuffle(&mut ids);
+
+    ids.iter()
+        .map(|id| {
+            let pass = (0..10)
+                .map(|bit| {
+                    let set = id >> (9 - bit) & 1 == 1;
+                    match (bit < 7, set) {
+                        (true, false) => 'F',
+                        (true, true) => 'B',
+                        (false, false) => 'L',
+                        (false, true) => 'R',
+                    }
+                })
+                .collect::<String>();
+            pass + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+//! Day 5: Binary Boarding. Decoding binary space partitioned boarding passes into seats.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::solution::{found, Solution};
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        found(part_one(input).cloned())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        found(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day05>(5, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The seat id of every boarding pass, one per line.
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_lines(input, |l| l.parse::<Seat>().map(|s| Seat::id(&s)))
+}
+
+/// The highest seat id.
+pub fn part_one(ids: &[u64]) -> Option<&u64> {
+    ids.iter().max()
+}
+
+/// The only seat id missing between the lowest and the highest.
+pub fn part_two(ids: &[u64]) -> Option<u64> {
+    let min = ids.iter().min()?;
+    let max = ids.iter().max()?;
+    let partial_sum: u64 = ids.iter().sum();
+
+    let min_sum = (min - 1) * min / 2;
+    let sum = max * (max + 1) / 2;
+    Some(sum - (partial_sum + min_sum))
+}
+
+/// A seat, read from a boarding pass like `FBFBBFFRLR`.
+#[derive(Debug, PartialEq)]
+pub struct Seat {
+    /// From 0 to 127, front to back.
+    pub row: u64,
+    /// From 0 to 7, left to right.
+    pub col: u64,
+}
+
+impl FromStr for Seat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 10 || !s.is_char_boundary(7) {
+            return Err(ParseError::new(s, s, "a seat like `FBFBBFFRLR`"));
+        }
+
+        let unknown = |i: usize| {
+            let c = s[i..].chars().next().map(char::len_utf8).unwrap_or(1);
+            &s[i..i + c]
+        };
+
+        let row = s[..7]
+            .bytes()
+            .enumerate()
+            .try_fold(0, |row, (i, c)| match c {
+                b'F' => Ok(row << 1),
+                b'B' => Ok(row << 1 | 1),
+                _ => Err(ParseError::new(s, unknown(i), "`F` or `B`")),
+            })?;
+
+        let col = s[7..]
+            .bytes()
+            .enumerate()
+            .try_fold(0, |col, (i, c)| match c {
+                b'L' => Ok(col << 1),
+                b'R' => Ok(col << 1 | 1),
+                _ => Err(ParseError::new(s, unknown(7 + i), "`L` or `R`")),
+            })?;
+
+        Ok(Seat { row, col })
+    }
+}
+
+impl Seat {
+    pub fn id(&self) -> u64 {
+        self.row * 8 + self.col
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn seat_from_str_test() {
+        assert_eq!(Seat::from_str("FBFBBFFRLR"), Ok(Seat { row: 44, col: 5 }));
+        assert_eq!(Seat::from_str("BFFFBBFRRR"), Ok(Seat { row: 70, col: 7 }));
+        assert_eq!(Seat::from_str("FFFBBBFRRR"), Ok(Seat { row: 14, col: 7 }));
+        assert_eq!(Seat::from_str("BBFFBBFRLL"), Ok(Seat { row: 102, col: 4 }));
+    }
+
+    #[test]
+    fn seat_id_test() {
+        assert_eq!(Seat::id(&Seat { row: 44, col: 5 }), 357);
+        assert_eq!(Seat::id(&Seat { row: 70, col: 7 }), 567);
+        assert_eq!(Seat::id(&Seat { row: 14, col: 7 }), 119);
+        assert_eq!(Seat::id(&Seat { row: 102, col: 4 }), 820);
+    }
+
+    #[test]
+    fn seat_from_str_errors_test() {
+        let e = Seat::from_str("FBFBXFFRLR").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (5, "X"));
+
+        let e = Seat::from_str("FBFBBFFRLF").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (10, "F"));
+
+        let e = parse("FBFBBFFRLR\nFBFBBFF").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "FBFBBFF"));
+    }
+}
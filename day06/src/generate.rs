@@ -0,0 +1,39 @@
+use aoc_common::generate::{Options, Rng};
+
+const QUESTIONS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// `size` groups of 1 to 5 people (480 by default), each answering yes to some questions,
+/// often the same ones as the rest of their group. Both parts always have an answer.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let groups = (0..options.size_or(480))
+        .map(|_| {
+            let mut common = QUESTIONS.to_vec();
+            rng.shuffle(&mut common);
+            common.truncate(rng.range(0..=10) as usize);
+
+            (0..rng.range(1..=5))
+                .map(|_| {
+                    let mut answers = common
+                        .iter()
+                        .filter(|_| rng.chance(0.8))
+                        .copied()
+                        .collect::<Vec<_>>();
+                    for _ in 0..rng.range(0..=5) {
+                        let question = *rng.choose(QUESTIONS);
+                        if !answers.contains(&question) {
+                            answers.push(question);
+                        }
+                    }
+                    if answers.is_empty() {
+                        answers.push(*rng.choose(QUESTIONS));
+                    }
+                    rng.shuffle(&mut answers);
+                    String::from_utf8_lossy(&answers).into_owned()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>();
+
+    groups.join("\n\n") + "\n"
+}
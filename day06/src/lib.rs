@@ -0,0 +1,121 @@
+//! Day 6: Custom Customs. Counting the questions answered yes by anyone, then by everyone, in each group.
+
+use aoc_common::day::Day;
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use std::collections::HashSet;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day06>(6, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The sum over the groups of the questions anyone answered yes to.
+pub fn part_one(input: &str) -> usize {
+    input
+        .split("\n\n")
+        .map(|group| {
+            group
+                .lines()
+                .map(|l| l.chars().collect::<HashSet<_>>())
+                .fold(HashSet::new(), |any_yes, yes| {
+                    any_yes.union(&yes).cloned().collect()
+                })
+                .len()
+        })
+        .sum()
+}
+
+/// The sum over the groups of the questions everyone answered yes to.
+pub fn part_two(input: &str) -> usize {
+    input
+        .split("\n\n")
+        .map(|group| {
+            group
+                .lines()
+                .map(|person| person.chars().collect::<HashSet<_>>())
+                .fold(('a'..='z').collect::<HashSet<_>>(), |all_yes, yes| {
+                    all_yes.intersection(&yes).cloned().collect()
+                })
+                .len()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        assert_eq!(
+            part_one(
+                "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+"
+            ),
+            11
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        assert_eq!(
+            part_two(
+                "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+"
+            ),
+            6
+        );
+    }
+}
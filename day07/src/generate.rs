@@ -0,0 +1,81 @@
+use aoc_common::generate::{Options, Rng};
+
+const TARGET: &str = "shiny gold";
+const LEVELS: usize = 8;
+const SYLLABLES: [&str; 16] = [
+    "ka", "lo", "mi", "ra", "te", "vu", "sa", "po", "ni", "de", "zu", "fe", "bo", "li", "ga", "ro",
+];
+
+/// Rules for `size` bag colors (600 by default). Bags only contain bags of the next levels of
+/// a few levels, which keeps the count of bags inside any bag reasonable. When solvable, some
+/// bags contain a shiny gold bag, which contains others.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(600).max(3);
+    let mut colors = (1..size).map(color).collect::<Vec<_>>();
+    colors.insert(
+        if options.solvable {
+            size / 2
+        } else {
+            rng.index(size)
+        },
+        TARGET.to_string(),
+    );
+
+    let level = |i: usize| i * LEVELS / size;
+    let target = colors.iter().position(|c| c == TARGET).unwrap_or(0);
+
+    let mut rules = (0..size)
+        .map(|i| {
+            let next_levels = (i + 1..size)
+                .filter(|&j| level(j) > level(i) && level(j) <= level(i) + 2)
+                .collect::<Vec<_>>();
+
+            let mut children = Vec::new();
+            if !next_levels.is_empty() && (rng.chance(0.8) || (options.solvable && i == target)) {
+                for _ in 0..rng.range(1..=4) {
+                    let child = *rng.choose(&next_levels);
+                    if !children.contains(&child) {
+                        children.push(child);
+                    }
+                }
+            }
+            if options.solvable && i + 1 == target && !children.contains(&target) {
+                children.push(target);
+            }
+
+            let contents = children
+                .iter()
+                .map(|&child| {
+                    let count = rng.range(1..=5);
+                    let bags = if count == 1 { "bag" } else { "bags" };
+                    format!("{} {} {}", count, colors[child], bags)
+                })
+                .collect::<Vec<_>>();
+
+            if contents.is_empty() {
+                format!("{} bags contain no other bags.\n", colors[i])
+            } else {
+                format!("{} bags contain {}.\n", colors[i], contents.join(", "))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rng.shuffle(&mut rules);
+    rules.concat()
+}
+
+/// A distinct two-word color for every `n`, made of syllables.
+fn color(n: usize) -> String {
+    let word = |mut n: usize| {
+        let mut word = String::new();
+        loop {
+            word += SYLLABLES[n % SYLLABLES.len()];
+            n /= SYLLABLES.len();
+            if n == 0 {
+                return word;
+            }
+        }
+    };
+
+    format!("{} {}", word(n % 64 + 64), word(n / 64))
+}
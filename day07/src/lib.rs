@@ -0,0 +1,281 @@
+//! Day 7: Handy Haversacks. Following the rules of which bags contain which bags.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::memo::Memo;
+use aoc_common::parser::{
+    integer, label, map, optional, or, pair, parse_all, preceded, recognize, separated, spaces1,
+    tag, take_while1, terminated, Parsed,
+};
+use aoc_common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Bags<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day07>(7, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// How many colors of bags eventually contain a shiny gold bag.
+pub fn part_one(bags: &Bags) -> usize {
+    let mut memo = Memo::new();
+    bags.keys()
+        .filter(|&c| *c != "shiny gold")
+        .filter(|c| eventually_contain_at_least_one_shiny_gold_bag(&mut memo, bags, c))
+        .count()
+}
+
+/// Whether a `color` bag is, or eventually contains, a shiny gold bag, `memo` keeping the
+/// answer for every color already explored.
+pub fn eventually_contain_at_least_one_shiny_gold_bag<'a>(
+    memo: &mut Memo<&'a str, bool>,
+    bags: &Bags<'a>,
+    color: &'a str,
+) -> bool {
+    memo.get(color, |memo, &color| {
+        (bags.contains_key(color) && color == "shiny gold")
+            || bags
+                .get(color)
+                .unwrap_or_else(|| panic!("No entry for color {:?}.", color))
+                .iter()
+                .any(|(_, children_color)| {
+                    eventually_contain_at_least_one_shiny_gold_bag(memo, bags, children_color)
+                })
+    })
+}
+
+/// How many bags a shiny gold bag contains.
+pub fn part_two(bags: &Bags) -> usize {
+    count_inside_bags(&mut Memo::new(), bags, "shiny gold")
+}
+
+/// How many bags a `color` bag contains, at any depth, `memo` keeping the count of every color
+/// already explored.
+pub fn count_inside_bags<'a>(
+    memo: &mut Memo<&'a str, usize>,
+    bags: &Bags<'a>,
+    color: &'a str,
+) -> usize {
+    memo.get(color, |memo, &color| {
+        bags.get(color)
+            .unwrap_or_else(|| panic!("No entry for color {:?}.", color))
+            .iter()
+            .map(|(count, children_color)| {
+                count + count * count_inside_bags(memo, bags, children_color)
+            })
+            .sum()
+    })
+}
+
+/// Every bag color with the count and color of the bags it directly contains.
+pub type Bags<'a> = HashMap<&'a str, Vec<(usize, &'a str)>>;
+
+/// One rule per line, like `light red bags contain 1 bright white bag, 2 muted yellow bags.`
+pub fn parse(s: &str) -> Result<Bags<'_>, ParseError> {
+    let rules = parse_lines(s, |l| parse_all(l, rule))?;
+
+    let bags = rules.iter().cloned().collect::<HashMap<_, _>>();
+
+    // Every bag inside another one must have its own rule.
+    if let Some((_, color)) = rules
+        .iter()
+        .flat_map(|(_, children)| children)
+        .find(|(_, color)| !bags.contains_key(color))
+    {
+        return Err(ParseError::new(s, color, "a color having its own rule"));
+    }
+
+    // No bag can eventually contain itself, its count of bags would never end.
+    match cycle(&bags, &rules) {
+        Some((child, color)) => Err(ParseError::new(
+            s,
+            child,
+            format!("a bag not eventually containing a {} bag", color),
+        )),
+        None => Ok(bags),
+    }
+}
+
+/// The first bag found inside a bag it eventually contains, with the color of that bag. Explores
+/// the rules in order, depth first without recursing.
+fn cycle<'a>(
+    bags: &Bags<'a>,
+    rules: &[(&'a str, Vec<(usize, &'a str)>)],
+) -> Option<(&'a str, &'a str)> {
+    let mut explored = HashSet::new();
+    // The colors being explored, from the rule's one down.
+    let mut path = HashSet::new();
+
+    for &(color, _) in rules {
+        if !explored.insert(color) {
+            continue;
+        }
+        path.insert(color);
+        // The same colors, with the index of their next child to explore.
+        let mut stack = vec![(color, 0)];
+
+        while let Some((color, next)) = stack.pop() {
+            match bags[color].get(next) {
+                Some(&(_, child)) => {
+                    stack.push((color, next + 1));
+                    if path.contains(child) {
+                        return Some((child, color));
+                    }
+                    if explored.insert(child) {
+                        path.insert(child);
+                        stack.push((child, 0));
+                    }
+                }
+                None => {
+                    path.remove(color);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// A rule, the color of the bag then the bags it contains.
+fn rule(s: &str) -> Parsed<'_, (&str, Vec<(usize, &str)>)> {
+    let children = or(
+        map(tag("no other bags"), |_| Vec::new()),
+        separated(
+            pair(
+                terminated(integer(), spaces1()),
+                terminated(color, pair(tag(" bag"), optional(tag("s")))),
+            ),
+            tag(", "),
+        ),
+    );
+
+    label(
+        pair(
+            terminated(color, tag(" bags contain ")),
+            terminated(children, tag(".")),
+        ),
+        "a rule like `bright white bags contain 1 shiny gold bag.`",
+    )(s)
+}
+
+/// A color of two words, like `shiny gold`.
+fn color(s: &str) -> Parsed<'_, &str> {
+    let word = || take_while1(char::is_alphabetic, "a color like `shiny gold`");
+    recognize(pair(word(), preceded(tag(" "), word())))(s)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+        assert_eq!(part_one(&parse(input).unwrap()), 4);
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+        assert_eq!(part_two(&parse(input).unwrap()), 32);
+
+        let input = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+        assert_eq!(part_two(&parse(input).unwrap()), 126);
+    }
+
+    #[test]
+    fn memoized_test() {
+        // Every color holds two of the next one, which a naive recursion explores 2^n times.
+        let colors = (0..40)
+            .map(|i| format!("dark {}", "x".repeat(i + 1)))
+            .collect::<Vec<_>>();
+        let mut input = String::from("shiny gold bags contain 2 dark x bags.\n");
+        for pair in colors.windows(2) {
+            input += &format!("{} bags contain 2 {} bags.\n", pair[0], pair[1]);
+        }
+        input += &format!("{} bags contain no other bags.\n", colors[39]);
+        let bags = parse(&input).unwrap();
+
+        let mut memo = Memo::new();
+        assert_eq!(
+            count_inside_bags(&mut memo, &bags, "shiny gold"),
+            (1 << 41) - 2
+        );
+        assert_eq!(memo.stats().misses, 41);
+        assert_eq!(part_one(&bags), 0);
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let input = "bright white bags contain 1 shiny gold bag.
+shiny gold bags contain 2 dark red bags.
+";
+        let e = parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 27, "dark red"));
+
+        let e = parse("bright white bags hold 1 shiny gold bag.").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = parse("light red bags contain 1 light red bag.").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 26, "light red"));
+
+        let input = "shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 dark blue bags, 1 faded blue bag.
+faded blue bags contain no other bags.
+dark blue bags contain 3 shiny gold bags.
+";
+        let e = parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 26, "shiny gold"));
+        assert_eq!(
+            e.expected,
+            "a bag not eventually containing a dark blue bag"
+        );
+    }
+}
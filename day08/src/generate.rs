@@ -0,0 +1,110 @@
+use aoc_common::generate::{Options, Rng};
+
+/// A boot code of `size` instructions (600 by default, at least 4) that never terminates.
+///
+/// When solvable, exactly one `jmp` or `nop` can be swapped to make it terminate. The program
+/// follows a path through some of the instructions, its last jump to the end being corrupted
+/// into a `nop` that falls back on the path. Every other instruction jumps back on the path, and
+/// no other swap can reach the end.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(600).max(4);
+
+    let instructions = if options.solvable {
+        solvable(rng, size)
+    } else {
+        (0..size)
+            .map(|i| match rng.below(3) {
+                0 => format!("acc {:+}", rng.range(0..=100) as i64 - 50),
+                1 => format!("jmp {:+}", offset(rng, i, size)),
+                _ => format!("nop {:+}", offset(rng, i, size)),
+            })
+            .collect()
+    };
+
+    instructions.iter().map(|i| format!("{}\n", i)).collect()
+}
+
+/// A random offset from `i` to an instruction of the program.
+fn offset(rng: &mut Rng, i: usize, size: usize) -> i64 {
+    rng.range(0..=size as u64 - 1) as i64 - i as i64
+}
+
+fn solvable(rng: &mut Rng, size: usize) -> Vec<String> {
+    // The path starts at 0 and never goes through the last instruction, so that swapping a
+    // `jmp` on it cannot fall through to the end.
+    // Instructions 1 and 2 are always on it, so that the corrupted instruction can fall back.
+    let mut others = (3..size - 1).collect::<Vec<_>>();
+    rng.shuffle(&mut others);
+    others.truncate(((size - 2) * 3 / 4).saturating_sub(2));
+    others.extend(&[1, 2]);
+    rng.shuffle(&mut others);
+
+    let mut path = vec![0];
+    path.extend(others);
+
+    // The corrupted instruction is last on the path, falling back on the path once a `nop`.
+    let last = (1..path.len())
+        .find(|&i| path.contains(&(path[i] + 1)))
+        .expect("Instruction 1 falls back on instruction 2");
+    let corrupted = path.remove(last);
+    path.push(corrupted);
+
+    let mut instructions = (0..size)
+        .map(|i| format!("jmp {:+}", -(i as i64)))
+        .collect::<Vec<_>>();
+
+    for step in path.windows(2) {
+        let (from, to) = (step[0], step[1]);
+        instructions[from] = if to != from + 1 {
+            format!("jmp {:+}", to as i64 - from as i64)
+        } else if rng.chance(0.5) {
+            format!("acc {:+}", rng.range(0..=100) as i64 - 50)
+        } else {
+            // Swapped into a `jmp`, it must neither leave the program nor reach the end.
+            format!("nop {:+}", offset(rng, from, size - 1))
+        };
+    }
+
+    instructions[corrupted] = format!("nop {:+}", size as i64 - corrupted as i64);
+
+    instructions
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{part_two, Instruction, Instructions};
+
+    /// How many single `jmp`/`nop` swaps make the program terminate.
+    fn fixes(instructions: &Instructions) -> usize {
+        (0..instructions.0.len())
+            .filter(|&i| {
+                let mut fixed = instructions.clone();
+                fixed.0[i] = match fixed.0[i] {
+                    Instruction::Acc(_) => return false,
+                    Instruction::Jmp(n) => Instruction::Nop(n),
+                    Instruction::Nop(n) => Instruction::Jmp(n),
+                };
+                !crate::is_looping(&fixed).0
+            })
+            .count()
+    }
+
+    #[test]
+    fn solvable_test() {
+        for (seed, size) in (0..50).zip((4..).step_by(7)) {
+            let options = Options {
+                size: Some(size),
+                solvable: true,
+            };
+            let input = generate(&mut Rng::new(seed), &options);
+            let instructions = input.parse::<Instructions>().unwrap();
+
+            assert_eq!(instructions.0.len(), size);
+            assert!(crate::is_looping(&instructions).0);
+            assert_eq!(fixes(&instructions), 1, "seed {}, size {}", seed, size);
+            assert!(part_two(instructions).is_some());
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 8: Handheld Halting. Running boot code, then fixing the one instruction that makes it loop.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::parser::{integer, label, map, or, pair, parse_all, preceded, spaces1, tag};
+use aoc_common::solution::{found, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Instructions;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        found(part_two(input.clone()))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day08>(8, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The accumulator right before an instruction runs a second time, an error when the program
+/// terminates instead.
+pub fn part_one(instructions: &Instructions) -> Result<i64, String> {
+    let (is_looping, program_state) = is_looping(instructions);
+
+    if !is_looping {
+        return Err("The program terminates, no instruction runs twice".to_string());
+    }
+
+    Ok(program_state.accumulator)
+}
+
+/// The accumulator once the program terminates, after swapping the one `jmp` or `nop` that
+/// makes it loop. None when no single swap makes it terminate.
+pub fn part_two(mut instructions: Instructions) -> Option<i64> {
+    for modify_index in 0..instructions.0.len() {
+        match instructions.0[modify_index] {
+            Instruction::Acc(_) => continue,
+            Instruction::Jmp(n) => {
+                instructions.0[modify_index] = Instruction::Nop(n);
+                let (is_looping, program_state) = is_looping(&instructions);
+                if is_looping {
+                    instructions.0[modify_index] = Instruction::Jmp(n);
+                } else {
+                    return Some(program_state.accumulator);
+                }
+            }
+            Instruction::Nop(n) => {
+                instructions.0[modify_index] = Instruction::Jmp(n);
+                let (is_looping, program_state) = is_looping(&instructions);
+                if is_looping {
+                    instructions.0[modify_index] = Instruction::Nop(n);
+                } else {
+                    return Some(program_state.accumulator);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Runs the program until an instruction would run a second time, or until it terminates by
+/// going past its last instruction. True with the state before the loop when it loops.
+pub fn is_looping(instructions: &Instructions) -> (bool, ProgramState) {
+    let mut program_state = ProgramState::default();
+    let mut instructions_seen = HashSet::new();
+
+    while !instructions_seen.contains(&program_state.pc) && program_state.pc < instructions.0.len()
+    {
+        instructions_seen.insert(program_state.pc);
+        program_state = step(&program_state, instructions);
+    }
+
+    (program_state.pc < instructions.0.len(), program_state)
+}
+
+/// An instruction of the boot code, like `jmp -3`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// Adds to the accumulator, then goes to the next instruction.
+    Acc(i64),
+    /// Jumps relatively to itself.
+    Jmp(i64),
+    /// Goes to the next instruction.
+    Nop(i64),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operation = label(
+            or(
+                map(tag("acc"), |_| Instruction::Acc as fn(i64) -> Instruction),
+                or(
+                    map(tag("jmp"), |_| Instruction::Jmp as fn(i64) -> Instruction),
+                    map(tag("nop"), |_| Instruction::Nop as fn(i64) -> Instruction),
+                ),
+            ),
+            "`acc`, `jmp` or `nop`",
+        );
+        let argument = label(preceded(spaces1(), integer()), "a signed number like `+1`");
+
+        parse_all(
+            s,
+            map(pair(operation, argument), |(instruction, n)| instruction(n)),
+        )
+    }
+}
+
+/// The boot code, one instruction per line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instructions(pub Vec<Instruction>);
+
+impl FromStr for Instructions {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(s, str::parse).map(Instructions)
+    }
+}
+
+/// Where the program is and what it accumulated so far.
+#[derive(Debug, Default, PartialEq)]
+pub struct ProgramState {
+    /// The index of the next instruction to run.
+    pub pc: usize,
+    pub accumulator: i64,
+}
+
+/// Runs the instruction at `pc`.
+pub fn step(p: &ProgramState, instructions: &Instructions) -> ProgramState {
+    match instructions.0[p.pc] {
+        Instruction::Acc(n) => ProgramState {
+            accumulator: p.accumulator + n,
+            pc: p.pc + 1,
+        },
+        Instruction::Jmp(n) => ProgramState {
+            pc: ((p.pc as i64 + n) as usize),
+            ..*p
+        },
+        Instruction::Nop(_) => ProgramState { pc: p.pc + 1, ..*p },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        let input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+        let instructions = input
+            .parse::<Instructions>()
+            .expect("Cannot read instructions");
+
+        assert_eq!(part_one(&instructions), Ok(5));
+
+        let terminating = "nop +0\nacc +1".parse::<Instructions>().unwrap();
+        assert!(part_one(&terminating).is_err());
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+        let instructions = input
+            .parse::<Instructions>()
+            .expect("Cannot read instructions");
+
+        assert_eq!(part_two(instructions), Some(8));
+
+        // Swapping either `jmp` still loops on the other one.
+        let unfixable = "jmp +0\njmp +0".parse::<Instructions>().unwrap();
+        assert_eq!(part_two(unfixable), None);
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let e = "nop +0\nacc +1\njpm +4"
+            .parse::<Instructions>()
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "jpm"));
+
+        let e = "nop +0\nacc 1x".parse::<Instructions>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "1x"));
+
+        let e = "nop +0\nacc".parse::<Instructions>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, ""));
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_common::generate::{Options, Rng};
+
+const PREAMBLE: usize = 25;
+
+/// A preamble of 25 numbers followed by numbers each the sum of two of the 25 before it,
+/// `size` numbers in all (1000 by default). When solvable, the last number breaks the rule and
+/// is the sum of a contiguous range of at least two numbers.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(1000).max(PREAMBLE + 1);
+
+    let mut numbers = (1..=2 * PREAMBLE as u64).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE);
+
+    while numbers.len() < size - 1 {
+        // Summing among the smallest numbers of the window keeps them from growing too fast.
+        let mut window = numbers[numbers.len() - PREAMBLE..].to_vec();
+        window.sort_unstable();
+        window.dedup();
+        let k = window.len().min(7);
+        let a = rng.index(k);
+        let b = (a + 1 + rng.index(k - 1)) % k;
+        numbers.push(window[a] + window[b]);
+    }
+
+    let last = if options.solvable {
+        weakness(rng, &numbers)
+    } else {
+        rng.range(1..=numbers[numbers.len() - 1] * 2)
+    };
+    numbers.push(last);
+
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+/// The sum of a contiguous range that no two numbers of the last window add up to.
+fn weakness(rng: &mut Rng, numbers: &[u64]) -> u64 {
+    let window = &numbers[numbers.len() - PREAMBLE..];
+
+    loop {
+        let len = rng.range(2..=17) as usize;
+        let start = rng.index(numbers.len() - len);
+        let sum = numbers[start..start + len].iter().sum();
+
+        if super::sum_two(window, sum).is_none() && !numbers.contains(&sum) {
+            return sum;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn solvable_test() {
+        for seed in 0..20 {
+            let options = Options {
+                size: Some(100 + seed as usize * 10),
+                solvable: true,
+            };
+            let numbers = parse(&generate(&mut Rng::new(seed), &options)).unwrap();
+
+            let invalid = part_one(&numbers, PREAMBLE);
+            assert_eq!(invalid, numbers.last().copied());
+            assert!(part_two(&numbers, invalid.unwrap()).is_some());
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Day 9: Encoding Error. Finding the number breaking the XMAS encoding, then the weakness it hides.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, parse_number, ParseError};
+use aoc_common::solution::{found, Solution};
+use std::collections::HashSet;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        found(part_one(input, 25))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        let invalid_number = Self::part_one(input)?;
+        found(part_two(input, invalid_number))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day09>(9, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The first number that is not the sum of two of the `window_length` numbers before it.
+pub fn part_one(numbers: &[u64], window_length: usize) -> Option<u64> {
+    for (i, window) in numbers.windows(window_length).enumerate() {
+        let target = numbers[window_length + i];
+        if sum_two(window, target).is_none() {
+            return Some(target);
+        }
+    }
+
+    None
+}
+
+/// The sum of the smallest and largest numbers of a contiguous range summing to `target`.
+pub fn part_two(numbers: &[u64], target: u64) -> Option<u64> {
+    let (start, end) = continuous_sum(numbers, target)?;
+    let numbers = &numbers[start..=end];
+    let min = numbers.iter().min()?;
+    let max = numbers.iter().max()?;
+    Some(min + max)
+}
+
+/// One number per line.
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_lines(input, |n| parse_number(n, n))
+}
+
+/// The first and last indices of a contiguous range summing to `target`.
+pub fn continuous_sum(numbers: &[u64], target: u64) -> Option<(usize, usize)> {
+    let mut start = 0;
+    let mut sum_start_end = numbers[start];
+
+    for end in 1..numbers.len() {
+        sum_start_end += numbers[end];
+
+        while sum_start_end > target && start < end {
+            sum_start_end -= numbers[start];
+            start += 1;
+        }
+
+        if sum_start_end == target {
+            return Some((start, end));
+        }
+    }
+
+    None
+}
+
+/// Two numbers summing to `target`.
+pub fn sum_two(numbers: &[u64], target: u64) -> Option<(u64, u64)> {
+    let mut seen = HashSet::new();
+
+    for &n in numbers {
+        seen.insert(n);
+        if target > n && seen.contains(&(target - n)) {
+            return Some((n, target - n));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        let input = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+        assert_eq!(part_one(&parse(input).unwrap(), 5), Some(127));
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+        assert_eq!(part_two(&parse(input).unwrap(), 127), Some(62));
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` distinct adapters (100 by default) chaining from the outlet, in any order. Runs of
+/// adapters 1 jolt apart are separated by 3 jolts gaps, with an occasional 2 jolts one, like in
+/// the puzzle. Any such bag of adapters is solvable.
+///
+/// The number of arrangements grows exponentially with the size: past a few hundreds adapters,
+/// it no longer fits 64 bits and needs the big-integer mode.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(100);
+    let mut adapters = Vec::with_capacity(size);
+    let mut joltage = 0;
+
+    while adapters.len() < size {
+        joltage += if rng.chance(0.1) { 2 } else { 3 };
+        for _ in 0..=rng.range(0..=4).min((size - adapters.len() - 1) as u64) {
+            adapters.push(joltage);
+            joltage += 1;
+        }
+        joltage -= 1;
+    }
+
+    rng.shuffle(&mut adapters);
+    adapters.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let adapters = parse(&input).unwrap();
+
+            assert_eq!(adapters.len(), 101);
+            assert!(adapters
+                .windows(2)
+                .all(|w| (1..=3).contains(&(w[1] - w[0]))));
+            assert!(part_one::<u64>(&adapters).is_ok());
+            assert!(part_two::<u64>(&adapters).unwrap().is_some());
+        }
+    }
+}
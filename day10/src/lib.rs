@@ -0,0 +1,252 @@
+//! Day 10: Adapter Array. Chaining joltage adapters, then counting the ways to arrange them.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, parse_number, ParseError};
+use aoc_common::memo::Memo;
+use aoc_common::num::{BigInt, Integer, Overflow};
+use aoc_common::solution::{found, Solution};
+use std::marker::PhantomData;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input in the arithmetic of
+/// `N`.
+pub struct Day10<N = u64>(PhantomData<N>);
+
+impl<N: Integer> Solution for Day10<N> {
+    type Input<'a> = Vec<usize>;
+    type PartOne = N;
+    type PartTwo = N;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        found(part_two(input)?)
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day10>(10, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_big::<Day10<BigInt>>();
+
+/// The number of 1-jolt differences times the number of 3-jolt differences, the device's
+/// built-in adapter included.
+pub fn part_one<N: Integer>(adapters: &[usize]) -> Result<N, Overflow> {
+    let jolt_differences = adapters
+        .windows(2)
+        .map(|adapters| adapters[1] - adapters[0] - 1)
+        .fold(
+            vec![
+                0, 0,
+                // built-in joltage adapter rated for 3 jolts higher than the highest-rated adapter in your bag.
+                1,
+            ],
+            |mut jolt_differences, diff| {
+                jolt_differences[diff] += 1;
+                jolt_differences
+            },
+        );
+
+    N::from_u64(jolt_differences[0])?.try_mul(&N::from_u64(jolt_differences[2])?)
+}
+
+/// The number of distinct arrangements of adapters connecting the outlet to the device.
+pub fn part_two<N: Integer>(adapters: &[usize]) -> Result<Option<N>, Overflow> {
+    arrangement_count(adapters)
+}
+
+/// The number of ways to reach the last of the sorted `adapters` from the first one.
+pub fn arrangement_count<N: Integer>(adapters: &[usize]) -> Result<Option<N>, Overflow> {
+    let last = match adapters.len().checked_sub(1) {
+        Some(last) => last,
+        None => return Ok(None),
+    };
+    let ways_from = |recurse: &mut dyn FnMut(usize) -> Result<N, Overflow>, &i: &usize| {
+        if i == last {
+            Ok(N::one())
+        } else {
+            (i + 1..adapters.len())
+                .take_while(|&j| adapters[j] - adapters[i] <= 3)
+                .try_fold(N::zero(), |ways, j| ways.try_add(&recurse(j)?))
+        }
+    };
+
+    // From the last adapter down, so that every adapter only needs the ones already counted and
+    // the recursion stays shallow.
+    let mut memo = Memo::new();
+    (0..=last)
+        .rev()
+        .map(|i| memo.solve(i, &ways_from))
+        .last()
+        .transpose()
+}
+
+/// The joltage of every adapter, one per line, sorted after the outlet's 0. Each adapter must
+/// be 1 to 3 jolts above the next lower one, or above the outlet.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut adapters = parse_lines(input, |n| Ok((parse_number::<usize>(n, n)?, n)))?;
+    // Equal joltages in the order of their lines, so that the later one is reported.
+    adapters.sort_by_key(|&(joltage, n)| (joltage, n.as_ptr()));
+
+    let mut previous = 0;
+    for (i, &(joltage, n)) in adapters.iter().enumerate() {
+        match joltage - previous {
+            0 if i == 0 => return Err(ParseError::new(input, n, "a joltage above the outlet's 0")),
+            0 => return Err(ParseError::new(input, n, "a joltage no other adapter has")),
+            4.. => {
+                return Err(ParseError::new(
+                    input,
+                    n,
+                    format!("a joltage 1 to 3 jolts above {}", previous),
+                ))
+            }
+            _ => previous = joltage,
+        }
+    }
+
+    Ok(std::iter::once(0)
+        .chain(adapters.into_iter().map(|(joltage, _)| joltage))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_test() {
+        let input = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+        assert_eq!(part_one::<u64>(&parse(input).unwrap()), Ok(35));
+
+        let input = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+        assert_eq!(part_one::<u64>(&parse(input).unwrap()), Ok(220));
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4
+";
+        assert_eq!(part_two::<u64>(&parse(input).unwrap()), Ok(Some(8)));
+
+        let input = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+        assert_eq!(part_two::<u64>(&parse(input).unwrap()), Ok(Some(19208)));
+    }
+
+    #[test]
+    fn overflow_test() {
+        // Every adapter one jolt above the previous one: the arrangements grow like the
+        // tribonacci numbers, past 64 bits within a hundred adapters.
+        let adapters = (0..=100).collect::<Vec<_>>();
+        assert_eq!(part_two::<u64>(&adapters), Err(Overflow));
+
+        let count = part_two::<u128>(&adapters).unwrap().unwrap();
+        let big = part_two::<BigInt>(&adapters).unwrap().unwrap();
+        assert_eq!(big.to_string(), count.to_string());
+        assert_eq!(part_two::<u64>(&adapters[..5]), Ok(Some(7)));
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let e = parse("1\n5").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "5"));
+        assert_eq!(e.expected, "a joltage 1 to 3 jolts above 1");
+
+        let e = parse("2\n1\n2").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "2"));
+        assert_eq!(e.expected, "a joltage no other adapter has");
+
+        let e = parse("1\n0").err().unwrap();
+        assert_eq!((e.line, e.text.as_str()), (2, "0"));
+
+        let e = parse("4").err().unwrap();
+        assert_eq!((e.line, e.text.as_str()), (1, "4"));
+    }
+}
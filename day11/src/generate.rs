@@ -0,0 +1,99 @@
+use super::{adjacents, first_see, round, Board, NeighborsStrategy, Position};
+use aoc_common::generate::{Options, Rng};
+use aoc_common::grid::Grid;
+
+/// Rounds after which a layout that neither settled nor repeated itself is given up on.
+const ROUNDS: usize = 10_000;
+
+/// A square seat layout `size` positions wide (90 by default), mostly empty seats `L` with some
+/// floor `.`.
+///
+/// Random layouts often end up with seats flipping forever between empty and occupied, which
+/// the puzzle's own inputs never do. Those seats are turned into floor until people settle
+/// with both rules, so that any layout is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(90).max(1);
+    let positions = (0..size * size)
+        .map(|_| {
+            if rng.chance(0.85) {
+                Position::Empty
+            } else {
+                Position::Floor
+            }
+        })
+        .collect();
+
+    let board = settle(Board {
+        grid: Grid::new(size, positions),
+    });
+
+    board.grid.render(|p| match p {
+        Position::Floor => '.',
+        Position::Empty => 'L',
+        Position::Occupied => '#',
+    })
+}
+
+fn settle(mut board: Board) -> Board {
+    loop {
+        let flipping = flipping(&board, 4, &adjacents)
+            .or_else(|| flipping(&board, 5, &first_see))
+            .unwrap_or_default();
+        if flipping.is_empty() {
+            return board;
+        }
+
+        for position in flipping {
+            board.grid[position] = Position::Floor;
+        }
+    }
+}
+
+/// The seats still changing once the rounds repeat themselves, None when people settle.
+fn flipping(
+    board: &Board,
+    tolerance: usize,
+    neighbors_strategy: &NeighborsStrategy,
+) -> Option<Vec<(usize, usize)>> {
+    let mut previous = board.clone();
+    let mut current = round(board, board.clone(), tolerance, neighbors_strategy);
+
+    for _ in 0..ROUNDS {
+        let next = round(&current, current.clone(), tolerance, neighbors_strategy);
+        if next == current {
+            return None;
+        }
+        if next == previous {
+            break;
+        }
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    let next = round(&current, current.clone(), tolerance, neighbors_strategy);
+    Some(
+        current
+            .grid
+            .positions()
+            .filter(|&p| current.grid[p] != next.grid[p])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..3 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let board = input.parse::<Board>().unwrap();
+
+            assert_eq!((board.grid.width(), board.grid.height()), (90, 90));
+            assert!(part_one(board.clone()) > 0);
+            assert!(part_two(board) > 0);
+        }
+    }
+}
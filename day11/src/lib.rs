@@ -0,0 +1,197 @@
+//! Day 11: Seating System. Simulating people taking and leaving seats until nobody moves.
+
+use aoc_common::day::Day;
+use aoc_common::error::ParseError;
+use aoc_common::grid::{Grid, DIRECTIONS_8};
+use aoc_common::solution::Solution;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Board;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input.clone()))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input.clone()))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day11>(11, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The occupied seats once people settle, looking at adjacent seats.
+pub fn part_one(board: Board) -> usize {
+    run_to_stabilizasion(board, 4, &adjacents).occupied_seat_count()
+}
+
+/// The occupied seats once people settle, looking at the first seat in every direction.
+pub fn part_two(board: Board) -> usize {
+    run_to_stabilizasion(board, 5, &first_see).occupied_seat_count()
+}
+
+fn run_to_stabilizasion(
+    mut board: Board,
+    tolerance: usize,
+    neighbors_strategy: &NeighborsStrategy,
+) -> Board {
+    let mut next_state = board.clone();
+
+    loop {
+        next_state = round(&board, next_state, tolerance, neighbors_strategy);
+        std::mem::swap(&mut next_state, &mut board);
+        if board == next_state {
+            break;
+        }
+    }
+
+    board
+}
+
+/// Counts the occupied seats around a seat that matter to whoever sits there.
+type NeighborsStrategy = dyn Fn(&Board, usize, usize) -> usize;
+
+/// A position of the seat layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+/// The seat layout, floor `.`, empty seats `L` and occupied seats `#`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Board {
+    grid: Grid<Position>,
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let position = |c| match c {
+            '.' => Some(Position::Floor),
+            'L' => Some(Position::Empty),
+            '#' => Some(Position::Occupied),
+            _ => None,
+        };
+
+        Ok(Board {
+            grid: Grid::parse(s, position, "`.`, `L` or `#`")?,
+        })
+    }
+}
+
+impl Board {
+    pub fn grid(&self) -> &Grid<Position> {
+        &self.grid
+    }
+
+    fn is_occupied(&self, (x, y): (usize, usize)) -> bool {
+        self.grid[(x, y)] == Position::Occupied
+    }
+
+    pub fn occupied_seat_count(&self) -> usize {
+        self.grid.count(|&p| p == Position::Occupied)
+    }
+}
+
+fn adjacents(board: &Board, x: usize, y: usize) -> usize {
+    board
+        .grid
+        .neighbours_8(x, y)
+        .filter(|&n| board.is_occupied(n))
+        .count()
+}
+
+fn first_see(board: &Board, x: usize, y: usize) -> usize {
+    DIRECTIONS_8
+        .iter()
+        .filter_map(|&d| board.grid.cast(x, y, d, |&p| p != Position::Floor))
+        .filter(|&n| board.is_occupied(n))
+        .count()
+}
+
+fn round(
+    current_state: &Board,
+    mut next_state: Board,
+    tolerance: usize,
+    neighbors_strategy: &NeighborsStrategy,
+) -> Board {
+    for ((x, y), &position) in current_state.grid.iter() {
+        next_state.grid[(x, y)] = match position {
+            // If a seat is empty (L) and there are no occupied seats adjacent to it, the seat becomes occupied.
+            Position::Empty if neighbors_strategy(current_state, x, y) == 0 => Position::Occupied,
+            // If a seat is occupied (#) and four or more seats adjacent to it are also occupied, the seat becomes empty
+            Position::Occupied if neighbors_strategy(current_state, x, y) >= tolerance => {
+                Position::Empty
+            }
+            // Otherwise, the seat's state does not change.
+            position => position,
+        };
+    }
+
+    next_state
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let board = "#.#L.L#.##
+#LLL#LL.L#
+L.#.L..#..
+#L##.##.L#
+#.#L.LL.LL
+#.#L#L#.##
+..L.L.....
+#L#L##L#L#
+#.LLLLLL.L
+#.#L#L#.##"
+            .parse::<Board>()
+            .unwrap();
+        assert_eq!(part_one(board), 37);
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let board = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+            .parse::<Board>()
+            .unwrap();
+        assert_eq!(part_two(board), 26);
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = "L.L\nL#x".parse::<Board>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+
+        let e = "L.L\nL#".parse::<Board>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "L#"));
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` navigation instructions (780 by default), turning by right angles only. Any such
+/// route is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(780);
+
+    (0..size)
+        .map(|_| match rng.below(7) {
+            0..=3 => format!(
+                "{}{}\n",
+                rng.choose(&["N", "S", "E", "W"]),
+                rng.range(1..=99)
+            ),
+            4 | 5 => format!("F{}\n", rng.range(1..=99)),
+            _ => format!(
+                "{}{}\n",
+                rng.choose(&["L", "R"]),
+                rng.choose(&[90, 180, 270])
+            ),
+        })
+        .collect()
+}
@@ -0,0 +1,365 @@
+//! Day 12: Rain Risk. Navigating a ferry, first by itself then by moving a waypoint.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, parse_number, ParseError};
+use aoc_common::num::{BigInt, Integer, Overflow};
+use aoc_common::solution::Solution;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input in the arithmetic of
+/// `N`, a signed integer.
+pub struct Day12<N = i64>(PhantomData<N>);
+
+impl<N: Integer> Solution for Day12<N> {
+    type Input<'a> = Vec<Movement>;
+    type PartOne = N;
+    type PartTwo = N;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        part_two(input)
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day12>(12, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_big::<Day12<BigInt>>();
+
+/// The Manhattan distance travelled moving the ship itself.
+pub fn part_one<N: Integer>(input: &[Movement]) -> Result<N, String> {
+    let p = input
+        .iter()
+        .try_fold(DirectedPosition::default(), |p, m| p.goto(m))?;
+
+    Ok(norm1(&p.x, &p.y)?)
+}
+
+/// The Manhattan distance travelled moving the ship towards a waypoint.
+pub fn part_two<N: Integer>(input: &[Movement]) -> Result<N, String> {
+    let w = Waypoint::start()?;
+    let p = DirectedPosition::default();
+    let p = input.iter().try_fold((p, w), |(p, w), m| w.goto(p, m))?.0;
+
+    Ok(norm1(&p.x, &p.y)?)
+}
+
+fn norm1<N: Integer>(x: &N, y: &N) -> Result<N, Overflow> {
+    x.try_abs()?.try_add(&y.try_abs()?)
+}
+
+/// One navigation instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Movement>, ParseError> {
+    parse_lines(input, str::parse)
+}
+
+/// A direction the ship can face or move to, `N`, `S`, `E` or `W`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Cardinal {
+    #[default]
+    East,
+    North,
+    South,
+    West,
+}
+
+impl FromStr for Cardinal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "E" => Ok(Cardinal::East),
+            "N" => Ok(Cardinal::North),
+            "S" => Ok(Cardinal::South),
+            "W" => Ok(Cardinal::West),
+            c => Err(format!("Unknow cardinal: {}", c)),
+        }
+    }
+}
+
+/// A turn, `L` or `R`.
+#[derive(Debug, Clone, Copy)]
+pub enum Rotation {
+    Left,
+    Right,
+}
+
+impl Rotation {
+    fn try_cardinal(&self, c: &Cardinal, degree: u64) -> Result<Cardinal, String> {
+        match (self, degree) {
+            (Rotation::Left, 90) | (Rotation::Right, 270) => match c {
+                Cardinal::East => Ok(Cardinal::North),
+                Cardinal::North => Ok(Cardinal::West),
+                Cardinal::South => Ok(Cardinal::East),
+                Cardinal::West => Ok(Cardinal::South),
+            },
+            (Rotation::Right, 90) | (Rotation::Left, 270) => match c {
+                Cardinal::East => Ok(Cardinal::South),
+                Cardinal::North => Ok(Cardinal::East),
+                Cardinal::South => Ok(Cardinal::West),
+                Cardinal::West => Ok(Cardinal::North),
+            },
+            (Rotation::Left, 180) | (Rotation::Right, 180) => match c {
+                Cardinal::East => Ok(Cardinal::West),
+                Cardinal::North => Ok(Cardinal::South),
+                Cardinal::South => Ok(Cardinal::North),
+                Cardinal::West => Ok(Cardinal::East),
+            },
+            (r, d) => Err(format!(
+                "Unknow rotation to cardinal convertion: {:?} {:?}",
+                r, d
+            )),
+        }
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Rotation::Left),
+            "R" => Ok(Rotation::Right),
+            c => Err(format!("Unknow rotation: {}", c)),
+        }
+    }
+}
+
+/// A navigation instruction, like `F10` or `R90`.
+#[derive(Debug)]
+pub enum Movement {
+    /// Moves towards a cardinal direction, whichever way the ship faces.
+    Absolute(Cardinal, u64),
+    /// Turns by a number of degrees.
+    Rotation(Rotation, u64),
+    /// Moves forward.
+    Forward(u64),
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let action_len = s.chars().next().map(char::len_utf8).unwrap_or(0);
+        let (c, n) = s.split_at(action_len);
+        if let Ok(cardinal) = c.parse() {
+            Ok(Movement::Absolute(cardinal, parse_number(s, n)?))
+        } else if let Ok(rotation) = c.parse() {
+            Ok(Movement::Rotation(rotation, parse_number(s, n)?))
+        } else if c == "F" {
+            Ok(Movement::Forward(parse_number(s, n)?))
+        } else {
+            Err(ParseError::new(
+                s,
+                c,
+                "an action among `N`, `S`, `E`, `W`, `L`, `R` or `F`",
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DirectedPosition<N> {
+    x: N,
+    y: N,
+    c: Cardinal,
+}
+
+impl<N: Integer> Default for DirectedPosition<N> {
+    fn default() -> Self {
+        DirectedPosition {
+            x: N::zero(),
+            y: N::zero(),
+            c: Cardinal::default(),
+        }
+    }
+}
+
+impl<N: Integer> DirectedPosition<N> {
+    fn goto_coordinates(self, c: &Cardinal, n: u64) -> Result<DirectedPosition<N>, Overflow> {
+        let n = N::from_u64(n)?;
+        Ok(match c {
+            Cardinal::East => DirectedPosition {
+                x: self.x.try_add(&n)?,
+                ..self
+            },
+            Cardinal::North => DirectedPosition {
+                y: self.y.try_add(&n)?,
+                ..self
+            },
+            Cardinal::South => DirectedPosition {
+                y: self.y.try_sub(&n)?,
+                ..self
+            },
+            Cardinal::West => DirectedPosition {
+                x: self.x.try_sub(&n)?,
+                ..self
+            },
+        })
+    }
+
+    fn inc(self, x: &N, y: &N) -> Result<DirectedPosition<N>, Overflow> {
+        Ok(DirectedPosition {
+            x: self.x.try_add(x)?,
+            y: self.y.try_add(y)?,
+            ..self
+        })
+    }
+
+    fn goto(self, m: &Movement) -> Result<DirectedPosition<N>, String> {
+        match m {
+            Movement::Absolute(cardinal, n) => Ok(self.goto_coordinates(cardinal, *n)?),
+            Movement::Forward(n) => {
+                let c = self.c;
+                Ok(self.goto_coordinates(&c, *n)?)
+            }
+            Movement::Rotation(rotation, degree) => {
+                let c = rotation.try_cardinal(&self.c, *degree)?;
+                let p = DirectedPosition { c, ..self };
+                Ok(p)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Waypoint<N> {
+    x: N,
+    y: N,
+}
+
+impl<N: Integer> Waypoint<N> {
+    /// Where the waypoint starts, 10 units east and 1 unit north of the ship.
+    fn start() -> Result<Waypoint<N>, Overflow> {
+        Ok(Waypoint {
+            x: N::from_u64(10)?,
+            y: N::one(),
+        })
+    }
+
+    fn goto_coordinates(self, c: &Cardinal, n: &N) -> Result<Waypoint<N>, Overflow> {
+        Ok(match c {
+            Cardinal::East => Waypoint {
+                x: self.x.try_add(n)?,
+                ..self
+            },
+            Cardinal::North => Waypoint {
+                y: self.y.try_add(n)?,
+                ..self
+            },
+            Cardinal::South => Waypoint {
+                y: self.y.try_sub(n)?,
+                ..self
+            },
+            Cardinal::West => Waypoint {
+                x: self.x.try_sub(n)?,
+                ..self
+            },
+        })
+    }
+
+    fn rotate(self, rotation: &Rotation, degree: u64) -> Result<Waypoint<N>, String> {
+        match (rotation, degree) {
+            (Rotation::Left, 90) | (Rotation::Right, 270) => Ok(Waypoint {
+                x: self.y.try_neg()?,
+                y: self.x,
+            }),
+            (Rotation::Right, 90) | (Rotation::Left, 270) => Ok(Waypoint {
+                y: self.x.try_neg()?,
+                x: self.y,
+            }),
+            (Rotation::Left, 180) | (Rotation::Right, 180) => Ok(Waypoint {
+                x: self.x.try_neg()?,
+                y: self.y.try_neg()?,
+            }),
+            (r, d) => Err(format!(
+                "Unknow rotation to cardinal convertion: {:?} {:?}",
+                r, d
+            )),
+        }
+    }
+
+    fn goto(
+        self,
+        p: DirectedPosition<N>,
+        m: &Movement,
+    ) -> Result<(DirectedPosition<N>, Waypoint<N>), String> {
+        match m {
+            Movement::Absolute(c, n) => {
+                let n = N::from_u64(*n)?;
+                let w = self.goto_coordinates(c, &n)?;
+                Ok((p, w))
+            }
+            Movement::Forward(n) => {
+                let n = N::from_u64(*n)?;
+                let dx = self.x.try_mul(&n)?;
+                let dy = self.y.try_mul(&n)?;
+                let p = p.inc(&dx, &dy)?;
+
+                Ok((p, self))
+            }
+            Movement::Rotation(rotation, degree) => {
+                let w = self.rotate(rotation, *degree)?;
+                Ok((p, w))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = "F10
+N3
+F7
+R90
+F11";
+        let input = parse(input).unwrap();
+        assert_eq!(part_one::<i64>(&input), Ok(25));
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let input = "F10
+N3
+F7
+R90
+F11";
+        let input = parse(input).unwrap();
+        assert_eq!(part_two::<i64>(&input), Ok(286));
+    }
+
+    #[test]
+    fn overflow_tests() {
+        let input = parse("F9223372036854775807\nE1").unwrap();
+        assert!(part_one::<i64>(&input).is_err());
+        assert_eq!(
+            part_one::<BigInt>(&input).map(|d| d.to_string()),
+            Ok("9223372036854775808".to_string())
+        );
+        assert_eq!(part_two::<i128>(&input), Ok(101457092405402533877));
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse("F10\nX3").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "X"));
+
+        let e = parse("F10\nR9o").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "9o"));
+    }
+}
@@ -0,0 +1,70 @@
+use aoc_common::generate::{Options, Rng};
+
+const PRIMES: [u64; 25] = [
+    7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101,
+    103, 107,
+];
+
+/// The earliest timestamp and a schedule of `size` slots (60 by default), a few of them being
+/// buses with distinct prime ids. The ids being pairwise coprime, every schedule is solvable;
+/// their product stays under 10^15 so that the timestamp of part two fits.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(60).max(1);
+    let mut primes = PRIMES.to_vec();
+    rng.shuffle(&mut primes);
+
+    let mut slots = vec![None; size];
+    let mut product = 1u64;
+    for &prime in &primes {
+        let slot = if slots[0].is_none() {
+            0
+        } else {
+            rng.index(size)
+        };
+        if slots[slot].is_some() || product * prime > 1_000_000_000_000_000 {
+            break;
+        }
+        slots[slot] = Some(prime);
+        product *= prime;
+    }
+
+    let schedule = slots
+        .iter()
+        .map(|slot| match slot {
+            Some(id) => id.to_string(),
+            None => "x".to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{}\n{}\n",
+        rng.range(100_000..=1_000_000),
+        schedule.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let (earliest_timestamp, buses) = parse(&input).unwrap();
+
+            assert_eq!(buses.len(), 60);
+            assert!(part_one::<u64>(earliest_timestamp, &buses)
+                .unwrap()
+                .is_some());
+            let timestamp = part_two::<u64>(&buses).unwrap();
+            for (offset, bus) in buses.iter().enumerate() {
+                if let Some(bus) = bus {
+                    assert_eq!((timestamp + offset as u64) % bus, 0);
+                }
+            }
+        }
+    }
+}
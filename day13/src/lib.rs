@@ -0,0 +1,177 @@
+//! Day 13: Shuttle Search. Finding the earliest bus, then the timestamp where buses leave in sequence.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_number, ParseError};
+use aoc_common::num::{BigInt, Integer, Overflow};
+use aoc_common::solution::{found, Solution};
+use std::marker::PhantomData;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input in the arithmetic of
+/// `N`.
+pub struct Day13<N = u64>(PhantomData<N>);
+
+impl<N: Integer> Solution for Day13<N> {
+    type Input<'a> = (u64, Vec<Option<u64>>);
+    type PartOne = N;
+    type PartTwo = N;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        let (earliest_timestamp, buses) = input;
+        found(part_one(*earliest_timestamp, buses)?)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(&input.1)?)
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day13>(13, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_big::<Day13<BigInt>>();
+
+/// The id of the earliest bus to take times the minutes to wait for it.
+pub fn part_one<N: Integer>(
+    earliest_timestamp: u64,
+    buses: &[Option<u64>],
+) -> Result<Option<N>, Overflow> {
+    buses
+        .iter()
+        .filter_map(|&b| b)
+        .map(|bus| (bus, bus - (earliest_timestamp % bus)))
+        .min_by_key(|&(_, time)| time)
+        .map(|(bus, time)| N::from_u64(bus)?.try_mul(&N::from_u64(time)?))
+        .transpose()
+}
+
+/// The earliest timestamp where every bus leaves as many minutes later as its offset in the
+/// schedule.
+pub fn part_two<N: Integer>(buses: &[Option<u64>]) -> Result<N, Overflow> {
+    // Chinese Remainder by sieving - https://www.dcode.fr/chinese-remainder
+    // Bus ids are pairwise coprime: once a timestamp fits the first buses, stepping by the
+    // product of their ids keeps it fitting them while looking for the next bus.
+    buses
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.map(|b| (i as u64, b)))
+        .try_fold(
+            (N::zero(), N::one()),
+            |(mut timestamp, step), (offset, bus)| {
+                let (offset, bus) = (N::from_u64(offset)?, N::from_u64(bus)?);
+                while timestamp.try_add(&offset)?.try_rem(&bus)? != N::zero() {
+                    timestamp = timestamp.try_add(&step)?;
+                }
+                Ok((timestamp, step.try_mul(&bus)?))
+            },
+        )
+        .map(|(timestamp, _)| timestamp)
+}
+
+/// The earliest timestamp, then the schedule of buses, `x` being out of service.
+pub fn parse(s: &str) -> Result<(u64, Vec<Option<u64>>), ParseError> {
+    let mut lines = s.lines();
+    let end = &s[s.len()..];
+
+    let earliest_timestamp = lines
+        .next()
+        .ok_or_else(|| ParseError::new(s, end, "the earliest timestamp"))?;
+    let earliest_timestamp = parse_number(s, earliest_timestamp)?;
+
+    let buses = lines
+        .next()
+        .ok_or_else(|| ParseError::new(s, end, "bus ids like `7,13,x,x,59`"))?
+        .split(',')
+        .map(|bus| match bus {
+            "x" => Ok(None),
+            bus => match parse_number(s, bus)? {
+                0 => Err(ParseError::new(s, bus, "a bus id greater than 0")),
+                id => Ok(Some(id)),
+            },
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((earliest_timestamp, buses))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = "939
+7,13,x,x,59,x,31,19";
+        let (earliest_timestamp, buses) = parse(input).unwrap();
+        assert_eq!(part_one::<u64>(earliest_timestamp, &buses), Ok(Some(295)));
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let input = "939
+7,13,x,x,59,x,31,19";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(1068781));
+
+        let input = "939
+17,x,13,19";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(3417));
+
+        let input = "939
+67,7,59,61";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(754018));
+
+        let input = "939
+67,x,7,59,61";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(779210));
+
+        let input = "939
+67,7,x,59,61";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(1261476));
+
+        let input = "939
+1789,37,47,1889";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Ok(1202161486));
+    }
+
+    #[test]
+    fn overflow_tests() {
+        // The product of the ids, and the timestamp with it, outgrows 64 bits.
+        let input = "939
+101,103,107,109,113,127,131,137,139,149";
+        let (_, buses) = parse(input).unwrap();
+        assert_eq!(part_two::<u64>(&buses), Err(Overflow));
+
+        let timestamp = part_two::<u128>(&buses).unwrap();
+        assert_eq!(
+            part_two::<BigInt>(&buses).unwrap().to_string(),
+            timestamp.to_string()
+        );
+        for (offset, bus) in buses.iter().enumerate() {
+            assert_eq!((timestamp + offset as u128) % bus.unwrap() as u128, 0);
+        }
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse("939\n7,13,x,y,59").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 8, "y"));
+
+        let e = parse("939\n7,0").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "0"));
+
+        let e = parse("939").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 4, ""));
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` instructions (550 by default): masks with at most 9 floating bits, so that a write
+/// of part two touches at most 512 addresses, each followed by a few writes. Any such program
+/// is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(550);
+    let mut instructions = Vec::with_capacity(size);
+
+    while instructions.len() < size {
+        let floating = rng.range(0..=9) as usize;
+        let mut mask = (0..36)
+            .map(|i| {
+                if i < floating {
+                    'X'
+                } else {
+                    *rng.choose(&['0', '1'])
+                }
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut mask);
+        instructions.push(format!("mask = {}\n", mask.iter().collect::<String>()));
+
+        for _ in 0..rng.range(1..=5).min((size - instructions.len()) as u64) {
+            instructions.push(format!(
+                "mem[{}] = {}\n",
+                rng.range(0..=65_535),
+                rng.range(0..=(1 << 36) - 1)
+            ));
+        }
+    }
+
+    instructions.concat()
+}
@@ -0,0 +1,282 @@
+//! Day 14: Docking Data. Writing to memory through bit masks, on values then on addresses.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::parser::{
+    and_then, integer, label, map, or, pair, parse_all, preceded, tag, take_while1, terminated,
+    Failure, Parsed,
+};
+use aoc_common::solution::Solution;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Instruction>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day14>(14, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The sum of the memory once the masks are applied to the values.
+pub fn part_one(instructions: &[Instruction]) -> u64 {
+    fn logic(mask: &[u8], mut memory: HashMap<u64, u64>, write: &Write) -> HashMap<u64, u64> {
+        let value = mask1(mask, write.value);
+        *memory.entry(write.address).or_insert(0) = value;
+        memory
+    }
+
+    part_inner(instructions, &logic)
+}
+
+/// The sum of the memory once the masks are applied to the addresses, floating bits writing
+/// to every address they stand for.
+pub fn part_two(instructions: &[Instruction]) -> u64 {
+    fn logic(mask: &[u8], memory: HashMap<u64, u64>, write: &Write) -> HashMap<u64, u64> {
+        let memory = Mask2::new(mask, write.address)
+            .iter()
+            .fold(memory, |mut memory, address| {
+                *memory.entry(address).or_insert(0) = write.value;
+                memory
+            });
+
+        memory
+    }
+
+    part_inner(instructions, &logic)
+}
+
+type PartFn = dyn Fn(&[u8], HashMap<u64, u64>, &Write) -> HashMap<u64, u64>;
+
+fn part_inner(instructions: &[Instruction], part_fn: &PartFn) -> u64 {
+    instructions
+        .iter()
+        .fold((HashMap::new(), &Vec::new()), |(memory, mask), i| match i {
+            Instruction::Mask(m) => (memory, m),
+            Instruction::Write(w) => (part_fn(mask, memory, w), mask),
+        })
+        .0
+        .values()
+        .sum()
+}
+
+fn mask1(mask: &[u8], value: u64) -> u64 {
+    mask.iter().rev().enumerate().fold(value, |v, (i, &bit)| {
+        if bit == b'1' {
+            1 << i | v
+        } else if bit == b'0' {
+            !(1 << i) & v
+        } else {
+            v
+        }
+    })
+}
+
+struct Mask2<'a> {
+    mask: &'a [u8],
+    address: u64,
+}
+
+impl<'a> Mask2<'a> {
+    fn new(mask: &[u8], address: u64) -> Mask2<'_> {
+        Mask2 { mask, address }
+    }
+
+    fn iter(&self) -> Mask2Iter {
+        let ternary_mask = self.mask.iter().rev().enumerate().fold(
+            Vec::new(),
+            |mut floating_address, (i, &bit)| {
+                if bit == b'1' {
+                    floating_address.push(b'1');
+                    floating_address
+                } else if bit == b'0' {
+                    if self.address & (1 << i) == 0 {
+                        floating_address.push(b'0');
+                    } else {
+                        floating_address.push(b'1');
+                    }
+                    floating_address
+                } else if bit == b'X' {
+                    floating_address.push(b'X');
+                    floating_address
+                } else {
+                    panic!("Incorrect mask {:?} at position {:?}", self.mask, i);
+                }
+            },
+        );
+
+        let state_count = 1 << ternary_mask.iter().filter(|&&x| x == b'X').count();
+        let state = 0;
+
+        Mask2Iter {
+            ternary_mask,
+            state_count,
+            state,
+        }
+    }
+}
+
+struct Mask2Iter {
+    ternary_mask: Vec<u8>,
+    state_count: u64,
+    state: u64,
+}
+
+impl Iterator for Mask2Iter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state >= self.state_count {
+            return None;
+        }
+
+        let address = self
+            .ternary_mask
+            .iter()
+            .fold((0, 0), |(address, i_state), &bit| {
+                if bit == b'1' {
+                    let address = address << 1 | 1;
+                    (address, i_state)
+                } else if bit == b'0' {
+                    let address = address << 1;
+                    (address, i_state)
+                } else {
+                    let inc = (self.state & (1 << i_state) == 0) as u64;
+                    let address = address << 1 | inc;
+                    (address, i_state + 1)
+                }
+            })
+            .0;
+
+        self.state += 1;
+
+        Some(address)
+    }
+}
+
+/// One instruction per line.
+pub fn parse(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(s, str::parse)
+}
+
+/// An instruction of the initialization program.
+#[derive(Debug)]
+pub enum Instruction {
+    /// A mask of 36 `X`, `0` or `1`, most significant bit first.
+    Mask(Vec<u8>),
+    Write(Write),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(
+            s,
+            or(map(mask, Instruction::Mask), map(write, Instruction::Write)),
+        )
+    }
+}
+
+/// `mask = ` then 36 `X`, `0` or `1`.
+fn mask(s: &str) -> Parsed<'_, Vec<u8>> {
+    let bits = and_then(
+        take_while1(|c: char| !c.is_whitespace(), "a mask of 36 bits"),
+        |mask: &str| {
+            if let Some(i) = mask.find(|c| !matches!(c, 'X' | '0' | '1')) {
+                let c = mask[i..].chars().next().unwrap_or_default();
+                Err(Failure::new(&mask[i..i + c.len_utf8()], "`X`, `0` or `1`"))
+            } else if mask.len() != 36 {
+                Err(Failure::new(mask, "a mask of 36 bits"))
+            } else {
+                Ok(mask.bytes().collect())
+            }
+        },
+    );
+
+    preceded(tag("mask = "), bits)(s)
+}
+
+/// A write to memory, like `mem[8] = 11`.
+#[derive(Debug)]
+pub struct Write {
+    pub address: u64,
+    pub value: u64,
+}
+
+impl FromStr for Write {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(s, write)
+    }
+}
+
+fn write(s: &str) -> Parsed<'_, Write> {
+    let address = label(
+        preceded(tag("mem["), terminated(integer(), tag("]"))),
+        "an address like `mem[8]`",
+    );
+
+    map(
+        pair(address, preceded(tag(" = "), integer())),
+        |(address, value)| Write { address, value },
+    )(s)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+        let instructions = parse(input).unwrap();
+        assert_eq!(part_one(&instructions), 165)
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let input = "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+        let instructions = parse(input).unwrap();
+        assert_eq!(part_two(&instructions), 208)
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 1x").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 10, "1x"));
+
+        let e = parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 42, "2"));
+
+        let e = parse("mem(8) = 11").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "mem(8)"));
+    }
+}
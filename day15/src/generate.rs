@@ -0,0 +1,12 @@
+use aoc_common::generate::{Options, Rng};
+
+/// `size` distinct starting numbers (7 by default, at least 1) below 20, like the puzzle's.
+/// Any starting numbers are solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut numbers = (0..20).collect::<Vec<u64>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(options.size_or(7).max(1));
+
+    let numbers = numbers.iter().map(u64::to_string).collect::<Vec<_>>();
+    format!("{}\n", numbers.join(","))
+}
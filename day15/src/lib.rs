@@ -0,0 +1,101 @@
+//! Day 15: Rambunctious Recitation. Playing the elves' memory game.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_number, ParseError};
+use aoc_common::solution::Solution;
+use std::collections::HashMap;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input, 2020))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_one(input, 30_000_000))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day15>(15, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_input("13,0,10,12,1,5,8");
+
+/// The `n`-th number spoken, starting from the given `numbers`.
+pub fn part_one(numbers: &[usize], n: usize) -> usize {
+    let mut game = numbers
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| (n, i + 1))
+        .collect::<HashMap<_, _>>();
+
+    let mut last_spoke = *numbers.last().expect("There is no input numbers.");
+
+    for i in numbers.len() + 1..=n {
+        match game.get_mut(&last_spoke) {
+            None => {
+                game.insert(last_spoke, i - 1);
+                last_spoke = 0;
+            }
+            Some(turns) => {
+                last_spoke = i - 1 - *turns;
+                *turns = i - 1;
+            }
+        }
+    }
+
+    last_spoke
+}
+
+/// Comma-separated starting numbers.
+pub fn parse(s: &str) -> Result<Vec<usize>, ParseError> {
+    s.trim_end()
+        .split(',')
+        .map(|n| parse_number(s, n))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse_ok(s: &str) -> Vec<usize> {
+        parse(s).unwrap()
+    }
+
+    #[test]
+    fn part_one_tests() {
+        assert_eq!(part_one(&parse_ok("0,3,6"), 10), 0);
+        assert_eq!(part_one(&parse_ok("0,3,6"), 2020), 436);
+        assert_eq!(part_one(&parse_ok("1,3,2"), 2020), 1);
+        assert_eq!(part_one(&parse_ok("2,1,3"), 2020), 10);
+        assert_eq!(part_one(&parse_ok("1,2,3"), 2020), 27);
+        assert_eq!(part_one(&parse_ok("2,3,1"), 2020), 78);
+        assert_eq!(part_one(&parse_ok("3,2,1"), 2020), 438);
+        assert_eq!(part_one(&parse_ok("3,1,2"), 2020), 1836);
+    }
+
+    #[test]
+    fn part_two_tests() {
+        assert_eq!(part_one(&parse_ok("0,3,6"), 30_000_000), 175594);
+        assert_eq!(part_one(&parse_ok("1,3,2"), 30_000_000), 2578);
+        assert_eq!(part_one(&parse_ok("2,1,3"), 30_000_000), 3544142);
+        assert_eq!(part_one(&parse_ok("1,2,3"), 30_000_000), 261214);
+        assert_eq!(part_one(&parse_ok("2,3,1"), 30_000_000), 6895259);
+        assert_eq!(part_one(&parse_ok("3,2,1"), 30_000_000), 18);
+        assert_eq!(part_one(&parse_ok("3,1,2"), 30_000_000), 362);
+    }
+}
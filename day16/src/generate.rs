@@ -0,0 +1,112 @@
+use aoc_common::generate::{Options, Rng};
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// The largest valid value: every value above is invalid for all the fields.
+const MAX: u64 = 10 * FIELDS.len() as u64;
+
+/// The 20 fields' rules, your ticket and `size` nearby tickets (240 by default), about a
+/// quarter of them with an invalid value.
+///
+/// The rule of the `k`-th field accepts the values up to `10 * (k + 1)`, and the first valid
+/// nearby ticket has a value above `10 * k` for it: every field then fits one more rule than the
+/// previous one, so that they can always be told apart.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(240).max(1);
+    let mut rules = (0..FIELDS.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+
+    let upper = |rule: usize| 10 * (rule as u64 + 1);
+    let split = |rule: usize| 5 * (rule as u64 + 1);
+    let ticket = |rng: &mut Rng, top: bool| {
+        rules
+            .iter()
+            .map(|&rule| {
+                let lowest = if top { upper(rule) - 9 } else { 1 };
+                rng.range(lowest..=upper(rule))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let your_ticket = ticket(rng, false);
+    let mut nearby_tickets = vec![ticket(rng, true)];
+    while nearby_tickets.len() < size {
+        let mut nearby_ticket = ticket(rng, false);
+        if rng.chance(0.25) {
+            let field = rng.index(nearby_ticket.len());
+            nearby_ticket[field] = rng.range(MAX + 1..=999);
+        }
+        nearby_tickets.push(nearby_ticket);
+    }
+    rng.shuffle(&mut nearby_tickets);
+
+    let mut text = String::new();
+    for (rule, name) in FIELDS.iter().enumerate() {
+        text += &format!(
+            "{}: 1-{} or {}-{}\n",
+            name,
+            split(rule),
+            split(rule) + 1,
+            upper(rule)
+        );
+    }
+    text += &format!("\nyour ticket:\n{}\n", join(&your_ticket));
+    text += "\nnearby tickets:\n";
+    for nearby_ticket in &nearby_tickets {
+        text += &format!("{}\n", join(nearby_ticket));
+    }
+    text
+}
+
+fn join(ticket: &[u64]) -> String {
+    ticket
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{part_one, part_two, Input};
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let options = Options {
+                size: Some(50),
+                solvable: true,
+            };
+            let input = generate(&mut Rng::new(seed), &options)
+                .parse::<Input>()
+                .unwrap();
+
+            assert_eq!(input.nearby_tickets.len(), 50);
+            part_one(&input.nearby_tickets, &input.rules);
+            assert!(part_two(&input) > 0);
+        }
+    }
+}
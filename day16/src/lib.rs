@@ -0,0 +1,270 @@
+//! Day 16: Ticket Translation. Validating tickets, then working out which field is which.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::parser::{
+    integer, label, map, pair, parse_all, preceded, separated, tag, take_while1, terminated,
+};
+use aoc_common::solution::Solution;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Input;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(&input.nearby_tickets, &input.rules))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day16>(16, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The sum of the values of nearby tickets that fit no rule.
+pub fn part_one(nearby_tickets: &[Ticket], rules: &[Rule]) -> u64 {
+    nearby_tickets
+        .iter()
+        .map(|ticket| errors(ticket, rules).into_iter().sum::<u64>())
+        .sum()
+}
+
+/// The product of the fields of your ticket whose name starts with `departure`.
+pub fn part_two(input: &Input) -> u64 {
+    let order = find_field_order(input)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(rule_order, rule_number)| {
+            if input.rules[rule_number].name.starts_with("departure") {
+                Some(rule_order)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    order.iter().map(|&i| input.your_ticket[i]).product()
+}
+
+/// The notes: the rules, your ticket and the nearby tickets.
+pub struct Input {
+    pub rules: Vec<Rule>,
+    pub your_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut blocks = s.split("\n\n");
+        let end = &s[s.len()..];
+
+        let rules = blocks
+            .next()
+            .ok_or_else(|| ParseError::new(s, end, "ticket rules"))?;
+        let rules = parse_lines(rules, str::parse).map_err(|e| e.within(s, rules))?;
+
+        let your_ticket = blocks
+            .next()
+            .ok_or_else(|| ParseError::new(s, end, "`your ticket:`"))?;
+        let your_ticket = parse_tickets(your_ticket, "your ticket:")
+            .map_err(|e| e.within(s, your_ticket))?
+            .pop()
+            .ok_or_else(|| ParseError::new(s, your_ticket, "one ticket after `your ticket:`"))?;
+
+        let nearby_tickets = blocks
+            .next()
+            .ok_or_else(|| ParseError::new(s, end, "`nearby tickets:`"))?;
+        let nearby_tickets = parse_tickets(nearby_tickets, "nearby tickets:")
+            .map_err(|e| e.within(s, nearby_tickets))?;
+
+        Ok(Input {
+            rules,
+            your_ticket,
+            nearby_tickets,
+        })
+    }
+}
+
+/// Parses a block of comma-separated tickets below its `header` line.
+fn parse_tickets(s: &str, header: &str) -> Result<Vec<Ticket>, ParseError> {
+    let (first, tickets) = s.split_once('\n').unwrap_or((s, &s[s.len()..]));
+    if first != header {
+        return Err(ParseError::new(s, first, format!("`{}`", header)));
+    }
+
+    parse_lines(tickets, |l| parse_all(l, separated(integer(), tag(","))))
+        .map_err(|e| e.within(s, tickets))
+}
+
+/// The values of a ticket, in the order of its fields.
+pub type Ticket = Vec<u64>;
+
+/// The two ranges of values a field accepts.
+pub type TicketRule = (RangeInclusive<u64>, RangeInclusive<u64>);
+
+fn is_ticket_rule_valid(rule: &TicketRule, v: &u64) -> bool {
+    rule.0.contains(v) || rule.1.contains(v)
+}
+
+/// A field and its valid values, like `class: 1-3 or 5-7`.
+pub struct Rule {
+    pub name: String,
+    pub ranges: TicketRule,
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = take_while1(|c: char| c.is_alphanumeric() || c == ' ', "a field name");
+        let range = || {
+            map(
+                pair(integer(), preceded(tag("-"), integer())),
+                |(begin, end)| RangeInclusive::new(begin, end),
+            )
+        };
+        let rule = map(
+            pair(
+                terminated(name, tag(": ")),
+                pair(range(), preceded(tag(" or "), range())),
+            ),
+            |(name, ranges)| Rule {
+                name: name.to_string(),
+                ranges,
+            },
+        );
+
+        parse_all(s, label(rule, "a rule like `class: 1-3 or 5-7`"))
+    }
+}
+
+fn errors<'a>(ticket: &'a Ticket, rules: &[Rule]) -> Vec<&'a u64> {
+    ticket
+        .iter()
+        .filter(|&field| {
+            !rules
+                .iter()
+                .any(|rule| is_ticket_rule_valid(&rule.ranges, field))
+        })
+        .collect()
+}
+
+fn ok_tickets(nearby_tickets: &[Ticket], rules: &[Rule]) -> Vec<Ticket> {
+    nearby_tickets
+        .iter()
+        .filter(|ticket| errors(ticket, rules).is_empty())
+        .cloned()
+        .collect()
+}
+
+fn valid_rules_per_field(rules: &[Rule], ok_tickets: &[Ticket]) -> Vec<HashSet<usize>> {
+    let len = rules.len();
+    (0..len).fold(Vec::new(), |mut all_valid_rules, field_number| {
+        let current_valid_rules = ok_tickets.iter().map(|t| t[field_number]).fold(
+            (0..len).collect::<HashSet<usize>>(),
+            |valid_rules, field| {
+                let valid_rule_for_one_field = (0..len)
+                    .filter(|&rule_number| is_ticket_rule_valid(&rules[rule_number].ranges, &field))
+                    .collect::<HashSet<usize>>();
+
+                valid_rules
+                    .intersection(&valid_rule_for_one_field)
+                    .cloned()
+                    .collect()
+            },
+        );
+
+        all_valid_rules.push(current_valid_rules);
+        all_valid_rules
+    })
+}
+
+fn reduce_field_order(field_possibilities: &[HashSet<usize>]) -> Vec<usize> {
+    let mut fields_order = vec![];
+    for i in 0..field_possibilities.len() {
+        let mut diff = field_possibilities[i].clone();
+        for j in 0..field_possibilities.len() {
+            if field_possibilities[j].len() < field_possibilities[i].len() {
+                diff = diff
+                    .difference(&field_possibilities[j])
+                    .cloned()
+                    .collect::<HashSet<usize>>();
+            }
+        }
+        debug_assert!(diff.len() == 1);
+        let remainder = *diff.iter().next().unwrap();
+        fields_order.push(remainder);
+    }
+
+    debug_assert!(fields_order.len() == 20);
+    fields_order
+}
+
+fn find_field_order(input: &Input) -> Vec<usize> {
+    let ok_tickets = ok_tickets(&input.nearby_tickets, &input.rules);
+    let field_possibilities = valid_rules_per_field(&input.rules, &ok_tickets);
+    reduce_field_order(&field_possibilities)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+        let input = input.parse::<Input>().unwrap();
+        assert_eq!(part_one(&input.nearby_tickets, &input.rules), 71)
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let input = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,x,50";
+        let e = input.parse::<Input>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (9, 4, "x"));
+
+        let e = "class: 1-3 or 5-7\nrow: 6-11 and 33-44"
+            .parse::<Input>()
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::{Options, Rng};
+
+/// A square slice of cubes `size` wide (8 by default), with at least one active cube. Any such
+/// slice is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size_or(8).max(1);
+    let mut cubes = (0..size * size)
+        .map(|_| if rng.chance(0.5) { b'#' } else { b'.' })
+        .collect::<Vec<_>>();
+    let active = rng.index(cubes.len());
+    cubes[active] = b'#';
+
+    cubes
+        .chunks(size)
+        .map(|row| String::from_utf8_lossy(row).into_owned() + "\n")
+        .collect()
+}
@@ -0,0 +1,233 @@
+//! Day 17: Conway Cubes. Simulating Conway cubes in 3 then 4 dimensions.
+
+use aoc_common::day::Day;
+use aoc_common::error::ParseError;
+use aoc_common::grid::Grid;
+use aoc_common::solution::Solution;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Conway;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        Ok(part_one(input.clone()))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        Ok(part_two(input.clone()))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day17>(17, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// The active cubes after 6 cycles in 3 dimensions.
+pub fn part_one(conway: Conway) -> usize {
+    (0..6).fold(conway, |conway, _| conway.next_3d()).alive()
+}
+
+/// The active cubes after 6 cycles in 4 dimensions.
+pub fn part_two(conway: Conway) -> usize {
+    (0..6).fold(conway, |conway, _| conway.next_4d()).alive()
+}
+
+/// The active cubes of the pocket dimension, read from a 2D slice of active `#` and inactive
+/// `.` cubes.
+#[derive(Clone)]
+pub struct Conway {
+    state: HashSet<Coordinate>,
+    min: Coordinate,
+    max: Coordinate,
+}
+
+impl Conway {
+    /// How many cubes are active.
+    pub fn alive(&self) -> usize {
+        self.state.len()
+    }
+
+    fn is_active(&self, c: &Coordinate) -> bool {
+        self.state.contains(c)
+    }
+
+    fn rules(
+        &self,
+        current: Coordinate,
+        active_neighbors: usize,
+        mut state: HashSet<Coordinate>,
+    ) -> HashSet<Coordinate> {
+        if self.is_active(&current) && (active_neighbors == 2 || active_neighbors == 3) {
+            state.insert(current);
+        } else {
+            if active_neighbors == 3 {
+                state.insert(current);
+            }
+        }
+        state
+    }
+
+    /// The next cycle, in 3 dimensions.
+    pub fn next_3d(self) -> Conway {
+        let mut state = HashSet::new();
+        for z in self.min.z..=self.max.z {
+            for y in self.min.y..=self.max.y {
+                for x in self.min.x..=self.max.x {
+                    let current = Coordinate { x, y, z, w: 0 };
+                    let active_neighbors = Coordinate::neighbors(&current)
+                        .iter()
+                        .filter(|n| n.w == 0)
+                        .filter(|n| self.is_active(n))
+                        .count();
+                    state = self.rules(current, active_neighbors, state);
+                }
+            }
+        }
+        Conway::new(state)
+    }
+
+    /// The next cycle, in 4 dimensions.
+    pub fn next_4d(self) -> Conway {
+        let mut state = HashSet::new();
+        for w in self.min.w..=self.max.w {
+            for z in self.min.z..=self.max.z {
+                for y in self.min.y..=self.max.y {
+                    for x in self.min.x..=self.max.x {
+                        let current = Coordinate { x, y, z, w };
+                        let active_neighbors = Coordinate::neighbors(&current)
+                            .iter()
+                            .filter(|c| self.is_active(c))
+                            .count();
+                        state = self.rules(current, active_neighbors, state);
+                    }
+                }
+            }
+        }
+
+        Conway::new(state)
+    }
+
+    fn new(state: HashSet<Coordinate>) -> Conway {
+        let min = Conway::boundaries_min(&state);
+        let max = Conway::boundaries_max(&state);
+        Conway { state, min, max }
+    }
+
+    fn boundaries_min(state: &HashSet<Coordinate>) -> Coordinate {
+        let x = state.iter().map(|c| c.x).min().unwrap() - 1;
+        let y = state.iter().map(|c| c.y).min().unwrap() - 1;
+        let z = state.iter().map(|c| c.z).min().unwrap() - 1;
+        let w = state.iter().map(|c| c.w).min().unwrap() - 1;
+        Coordinate { x, y, z, w }
+    }
+
+    fn boundaries_max(state: &HashSet<Coordinate>) -> Coordinate {
+        let x = state.iter().map(|c| c.x).max().unwrap() + 1;
+        let y = state.iter().map(|c| c.y).max().unwrap() + 1;
+        let z = state.iter().map(|c| c.z).max().unwrap() + 1;
+        let w = state.iter().map(|c| c.w).max().unwrap() + 1;
+        Coordinate { x, y, z, w }
+    }
+}
+
+impl FromStr for Conway {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slice = Grid::parse(
+            s,
+            |c| matches!(c, '.' | '#').then_some(c == '#'),
+            "`.` or `#`",
+        )?;
+
+        let state = slice
+            .iter()
+            .filter(|&(_, &active)| active)
+            .map(|((x, y), _)| Coordinate {
+                x: x as isize,
+                y: y as isize,
+                z: 0,
+                w: 0,
+            })
+            .collect::<HashSet<_>>();
+
+        if state.is_empty() {
+            return Err(ParseError::new(s, s, "at least one active cube `#`"));
+        }
+
+        Ok(Conway::new(state))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Coordinate {
+    x: isize,
+    y: isize,
+    z: isize,
+    w: isize,
+}
+
+impl Coordinate {
+    fn neighbors(c: &Coordinate) -> Vec<Coordinate> {
+        let mut neighbors = Vec::new();
+        for w in c.w - 1..=c.w + 1 {
+            for z in c.z - 1..=c.z + 1 {
+                for y in c.y - 1..=c.y + 1 {
+                    for x in c.x - 1..=c.x + 1 {
+                        let neighbor = Coordinate { x, y, z, w };
+                        if *c != neighbor {
+                            neighbors.push(neighbor)
+                        }
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = ".#.
+..#
+###";
+
+        let input = input.parse::<Conway>().expect("Cannot parse input");
+        assert_eq!(part_one(input), 112)
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let input = ".#.
+..#
+###";
+
+        let input = input.parse::<Conway>().expect("Cannot parse input");
+        assert_eq!(part_two(input), 848)
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = ".#.\n.o#".parse::<Conway>().err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "o"));
+
+        let e = "...\n...".parse::<Conway>().err().unwrap();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
@@ -0,0 +1,41 @@
+use aoc_common::generate::{Options, Rng};
+
+/// At most 12 numbers per expression: every number being below 10, an expression is then
+/// below 10^12 with either precedence, and their sum cannot overflow.
+const NUMBERS: usize = 12;
+
+/// `size` expressions (370 by default) of single digits, additions, multiplications and
+/// parentheses nested up to twice. Any expression is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    (0..options.size_or(370))
+        .map(|_| {
+            let mut budget = NUMBERS;
+            expression(rng, 0, &mut budget) + "\n"
+        })
+        .collect()
+}
+
+/// An expression of at least two terms, spending at most `budget` numbers.
+fn expression(rng: &mut Rng, depth: usize, budget: &mut usize) -> String {
+    let terms = rng.range(2..=if depth == 0 { 5 } else { 3 });
+    let mut expression = term(rng, depth, budget);
+
+    for _ in 1..terms {
+        if *budget == 0 {
+            break;
+        }
+        let operator = if rng.chance(0.5) { '+' } else { '*' };
+        expression += &format!(" {} {}", operator, term(rng, depth, budget));
+    }
+
+    expression
+}
+
+fn term(rng: &mut Rng, depth: usize, budget: &mut usize) -> String {
+    if depth < 2 && *budget >= 3 && rng.chance(0.3) {
+        format!("({})", expression(rng, depth + 1, budget))
+    } else {
+        *budget -= 1;
+        rng.range(1..=9).to_string()
+    }
+}
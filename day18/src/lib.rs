@@ -0,0 +1,314 @@
+//! Day 18: Operation Order. Evaluating expressions with unusual precedence rules.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::num::{BigInt, Integer, Overflow};
+use aoc_common::solution::Solution;
+use std::marker::PhantomData;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input in the arithmetic of
+/// `N`.
+pub struct Day18<N = u64>(PhantomData<N>);
+
+impl<N: Integer> Solution for Day18<N> {
+    type Input<'a> = &'a str;
+    type PartOne = N;
+    type PartTwo = N;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        // Both precedences accept the same expressions: checking one of them is enough.
+        parse::<N>(input, 1)?;
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        part_two(input)
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day = Day::new::<Day18>(18, env!("CARGO_MANIFEST_DIR"))
+    .with_generator(generate::generate)
+    .with_big::<Day18<BigInt>>();
+
+/// The sum of the expressions, one per line, `+` and `*` having the same precedence.
+pub fn part_one<N: Integer>(s: &str) -> Result<N, String> {
+    let expressions = parse(s, 1).map_err(|e| e.to_string())?;
+    Ok(sum(expressions)?)
+}
+
+/// The sum of the expressions, one per line, `+` having precedence over `*`.
+pub fn part_two<N: Integer>(s: &str) -> Result<N, String> {
+    let expressions = parse(s, 2).map_err(|e| e.to_string())?;
+    Ok(sum(expressions)?)
+}
+
+fn sum<N: Integer>(expressions: Vec<Expression<N>>) -> Result<N, Overflow> {
+    expressions
+        .into_iter()
+        .try_fold(N::zero(), |sum, e| sum.try_add(&eval(e)?))
+}
+
+/// One expression per line, with the precedence rules of `part` 1 or 2 and the numbers of `N`.
+pub fn parse<N: Integer>(s: &str, part: u8) -> Result<Vec<Expression<N>>, ParseError> {
+    parse_lines(s, |l| Parser::new(l, part).parse())
+}
+
+/// An expression tree, parentheses being implied by its shape.
+#[derive(Debug)]
+pub enum Expression<N> {
+    Number(N),
+    Addition(Box<Expression<N>>, Box<Expression<N>>),
+    Multiplication(Box<Expression<N>>, Box<Expression<N>>),
+}
+
+/// A recursive descent parser of one line of homework.
+pub struct Parser<'a, N> {
+    text: &'a str,
+    // Non whitespace characters with their byte offset in `text`.
+    data: Vec<(usize, char)>,
+    current: usize,
+    part: u8,
+    number: PhantomData<N>,
+}
+
+impl<'a, N: Integer> Parser<'a, N> {
+    /// A parser of `text` with the precedence rules of `part` 1 or 2.
+    pub fn new(text: &'a str, part: u8) -> Self {
+        let data = text
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .collect::<Vec<_>>();
+        let current = 0;
+
+        Parser {
+            text,
+            data,
+            current,
+            part,
+            number: PhantomData,
+        }
+    }
+
+    /// The whole text as one expression.
+    pub fn parse(&mut self) -> Result<Expression<N>, ParseError> {
+        let expression = self.expression()?;
+        if self.is_end() {
+            Ok(expression)
+        } else {
+            Err(self.error("`+`, `*` or the end of the line"))
+        }
+    }
+
+    fn is_end(&self) -> bool {
+        self.current == self.data.len()
+    }
+
+    fn next(&mut self) -> char {
+        let c = self.data[self.current].1;
+        self.current += 1;
+        c
+    }
+
+    fn number(&mut self) -> Result<Expression<N>, ParseError> {
+        // Digits separated by whitespace are two numbers, not one.
+        let start = self.offset(self.current);
+        let end = self.data[self.current..]
+            .iter()
+            .enumerate()
+            .position(|(i, &(offset, c))| !c.is_ascii_digit() || offset != start + i)
+            .map(|i| i + self.current)
+            .unwrap_or(self.data.len());
+
+        if end == self.current {
+            return Err(self.error("a number or `(`"));
+        }
+
+        let (last, digit) = self.data[end - 1];
+        let text = &self.text[start..last + digit.len_utf8()];
+        let n = text.parse::<N>().map_err(|_| {
+            ParseError::new(self.text, text, "a number fitting the checked arithmetic")
+        })?;
+
+        self.current = end;
+        Ok(Expression::Number(n))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.data.get(self.current).map(|&(_, c)| c)
+    }
+
+    /// Byte offset in `text` of the character at `index`, or the end of `text`.
+    fn offset(&self, index: usize) -> usize {
+        self.data
+            .get(index)
+            .map(|&(i, _)| i)
+            .unwrap_or_else(|| self.text.len())
+    }
+
+    /// An error about the current character, or about the missing end of the line.
+    fn error(&self, expected: &str) -> ParseError {
+        let start = self.offset(self.current);
+        let end = self.peek().map(|c| start + c.len_utf8()).unwrap_or(start);
+        ParseError::new(self.text, &self.text[start..end], expected)
+    }
+
+    fn parentheses(&mut self) -> Result<Expression<N>, ParseError> {
+        if self.peek() == Some('(') {
+            self.next();
+            let expr = self.expression()?;
+            if self.peek() != Some(')') {
+                return Err(self.error("`)`"));
+            }
+            self.next();
+            Ok(expr)
+        } else {
+            self.number()
+        }
+    }
+
+    fn addition_or_multiplication(&mut self) -> Result<Expression<N>, ParseError> {
+        let mut expression = self.parentheses()?;
+
+        while self.peek() == Some('*') || self.peek() == Some('+') {
+            let c = self.next();
+            if c == '*' {
+                let rhs = self.parentheses()?;
+                expression = Expression::Multiplication(Box::new(expression), Box::new(rhs))
+            } else if c == '+' {
+                let rhs = self.parentheses()?;
+                expression = Expression::Addition(Box::new(expression), Box::new(rhs))
+            }
+        }
+
+        Ok(expression)
+    }
+
+    fn addition(&mut self) -> Result<Expression<N>, ParseError> {
+        let mut expression = self.parentheses()?;
+
+        while self.peek() == Some('+') {
+            self.next();
+            let rhs = self.parentheses()?;
+            expression = Expression::Addition(Box::new(expression), Box::new(rhs))
+        }
+
+        Ok(expression)
+    }
+
+    fn multiplication(&mut self) -> Result<Expression<N>, ParseError> {
+        let mut expression = self.addition()?;
+
+        while self.peek() == Some('*') {
+            self.next();
+            let rhs = self.addition()?;
+            expression = Expression::Multiplication(Box::new(expression), Box::new(rhs))
+        }
+
+        Ok(expression)
+    }
+
+    fn expression(&mut self) -> Result<Expression<N>, ParseError> {
+        if self.part == 1 {
+            // expression -> addition_or_multiplication
+            // addition_or_multiplication -> parentheses (("+" | "*") parentheses)*
+            // parentheses -> "(" expression ")" | digit
+            // digit -> [0..9]+
+            self.addition_or_multiplication()
+        } else {
+            // expression -> multiplication
+            // multiplication -> addition ("*" addition)*
+            // addition -> parentheses ("+" parentheses)*
+            // parentheses -> "(" expression ")" | digit
+            // digit -> [0..9]+
+            self.multiplication()
+        }
+    }
+}
+
+/// The value of an expression, failing when it overflows `N`.
+pub fn eval<N: Integer>(expr: Expression<N>) -> Result<N, Overflow> {
+    match expr {
+        Expression::Number(n) => Ok(n),
+        Expression::Addition(l, r) => eval::<N>(*l)?.try_add(&eval(*r)?),
+        Expression::Multiplication(l, r) => eval::<N>(*l)?.try_mul(&eval(*r)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        assert_eq!(part_one::<u64>("1 + 2 * 3 + 4 * 5 + 6"), Ok(71));
+        assert_eq!(part_one::<u64>("1 + (2 * 3) + (4 * (5 + 6))"), Ok(51));
+        assert_eq!(part_one::<u64>("2 * 3 + (4 * 5)"), Ok(26));
+        assert_eq!(part_one::<u64>("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Ok(437));
+        assert_eq!(
+            part_one::<u64>("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(12240)
+        );
+        assert_eq!(
+            part_one::<u64>("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(13632)
+        );
+    }
+
+    #[test]
+    fn part_two_tests() {
+        assert_eq!(part_two::<u64>("1 + 2 * 3 + 4 * 5 + 6"), Ok(231));
+        assert_eq!(part_two::<u64>("1 + (2 * 3) + (4 * (5 + 6))"), Ok(51));
+        assert_eq!(part_two::<u64>("2 * 3 + (4 * 5)"), Ok(46));
+        assert_eq!(part_two::<u64>("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Ok(1445));
+        assert_eq!(
+            part_two::<u64>("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(669060)
+        );
+        assert_eq!(
+            part_two::<u64>("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(23340)
+        );
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse::<u64>("1 + 2\n(3 * 4 + 5", 1).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 11, ""));
+
+        let e = parse::<u64>("1 + 2\n3 * a", 2).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "a"));
+
+        let e = parse::<u64>("1 2", 2).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 3, "2"));
+    }
+
+    #[test]
+    fn overflow_tests() {
+        let input = "4000000000 * 5000000000 + 1\n2";
+        assert!(part_one::<u64>(input).is_err());
+        assert_eq!(
+            part_one::<BigInt>(input).unwrap().to_string(),
+            "20000000000000000003"
+        );
+        assert_eq!(part_two::<u128>(input), Ok(20000000004000000002));
+
+        // 2^64, a number the checked arithmetic cannot read.
+        let input = "18446744073709551616 + 1";
+        let e = parse::<u64>(input, 1).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str()),
+            (1, 1, "18446744073709551616")
+        );
+        assert_eq!(
+            part_two::<BigInt>(input).unwrap().to_string(),
+            "18446744073709551617"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::generate::{Options, Rng};
+use std::collections::HashMap;
+
+/// The length of the messages matched by rules 42 and 31.
+const CHUNK: usize = 5;
+
+/// Rules `0: 8 11`, `8: 42` and `11: 42 31` as in the puzzle, rules 42 and 31 matching two
+/// complementary sets of `a`/`b` chunks through a tree of randomly numbered rules, then `size`
+/// messages (450 by default). Most messages are some chunks of rule 42 followed by fewer
+/// chunks of rule 31, within what part two handles, the others being random. Any such input
+/// is solvable.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut chunks = (0..1 << CHUNK)
+        .map(|bits: usize| {
+            (0..CHUNK)
+                .map(|i| if bits >> i & 1 == 1 { b'b' } else { b'a' })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut chunks);
+    let (chunks_42, chunks_31) = chunks.split_at(chunks.len() / 2);
+
+    let mut grammar = Grammar::new(rng);
+    let rule_42 = grammar.rule(rng, chunks_42.to_vec());
+    let rule_31 = grammar.rule(rng, chunks_31.to_vec());
+    let mut rules = grammar.rules;
+    rules.extend(vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        format!("42: {}", rule_42),
+        format!("31: {}", rule_31),
+    ]);
+    rng.shuffle(&mut rules);
+
+    let messages = (0..options.size_or(450))
+        .map(|_| {
+            if rng.chance(0.7) {
+                let inner = rng.range(1..=3);
+                let outer = inner + rng.range(1..=3);
+                let mut message = Vec::new();
+                for i in 0..outer + inner {
+                    let chunks = if i < outer { chunks_42 } else { chunks_31 };
+                    message.extend(rng.choose(chunks));
+                }
+                // Sometimes one letter off, which no rule matches anymore.
+                if rng.chance(0.2) {
+                    let i = rng.index(message.len());
+                    message[i] = if message[i] == b'a' { b'b' } else { b'a' };
+                }
+                String::from_utf8_lossy(&message).into_owned()
+            } else {
+                let len = rng.range(1..=8) as usize * CHUNK;
+                rng.string(b"ab", len)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!("{}\n\n{}\n", rules.join("\n"), messages.join("\n"))
+}
+
+/// Rules matching sets of chunks, numbered randomly apart from the puzzle's own rules.
+struct Grammar {
+    numbers: Vec<u64>,
+    rules: Vec<String>,
+    letters: HashMap<u8, u64>,
+}
+
+impl Grammar {
+    fn new(rng: &mut Rng) -> Grammar {
+        let mut numbers = (1..200)
+            .filter(|n| ![8, 11, 31, 42].contains(n))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+
+        let mut grammar = Grammar {
+            numbers,
+            rules: Vec::new(),
+            letters: HashMap::new(),
+        };
+        for &letter in b"ab" {
+            let number = grammar.number();
+            grammar
+                .rules
+                .push(format!("{}: \"{}\"", number, letter as char));
+            grammar.letters.insert(letter, number);
+        }
+        grammar
+    }
+
+    fn number(&mut self) -> u64 {
+        self.numbers.pop().expect("Not enough rule numbers")
+    }
+
+    /// The number of a rule matching exactly the given chunks, all of the same length.
+    ///
+    /// Alternatives start with distinct letters, so that matching never has to backtrack into
+    /// a rule.
+    fn rule(&mut self, rng: &mut Rng, chunks: Vec<Vec<u8>>) -> u64 {
+        let mut alternatives = Vec::new();
+        for &letter in b"ab" {
+            let tails = chunks
+                .iter()
+                .filter(|c| c[0] == letter)
+                .map(|c| c[1..].to_vec())
+                .collect::<Vec<_>>();
+            if tails.is_empty() {
+                continue;
+            }
+            let mut alternative = self.letters[&letter].to_string();
+            if !tails[0].is_empty() {
+                alternative += &format!(" {}", self.rule(rng, tails));
+            }
+            alternatives.push(alternative);
+        }
+        rng.shuffle(&mut alternatives);
+
+        let number = self.number();
+        self.rules
+            .push(format!("{}: {}", number, alternatives.join(" | ")));
+        number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let (rules, messages) = parse(&input).unwrap();
+
+            assert_eq!(messages.len(), 450);
+            let valid = part_two(rules.clone(), &messages);
+            assert!(part_one(&rules, &messages) < valid);
+            assert!(valid < messages.len());
+        }
+    }
+}
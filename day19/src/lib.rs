@@ -0,0 +1,265 @@
+//! Day 19: Monster Messages. Matching messages against a grammar, then against a recursive one.
+
+use aoc_common::day::Day;
+use aoc_common::error::{parse_lines, ParseError};
+use aoc_common::memo::Memo;
+use aoc_common::parser::{
+    and_then, integer, many1, map, optional, or, pair, parse_all, preceded, recognize, separated,
+    spaces1, tag, take_while1, terminated, Failure, Parsed,
+};
+use aoc_common::solution::Solution;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// The `Solution` of the day, solving both parts from the puzzle input.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Rules, Messages);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, String> {
+        let (rules, messages) = input;
+        Ok(part_one(rules, messages))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, String> {
+        let (rules, messages) = input;
+        Ok(part_two(rules.clone(), messages))
+    }
+}
+
+/// The day as the runner and the binary see it.
+pub const DAY: Day =
+    Day::new::<Day19>(19, env!("CARGO_MANIFEST_DIR")).with_generator(generate::generate);
+
+/// How many messages match rule 0.
+pub fn part_one(rules: &Rules, messages: &[Message]) -> usize {
+    messages.iter().filter(|m| is_valid(rules, m)).count()
+}
+
+/// How many messages match rule 0 once rules 8 and 11 loop.
+pub fn part_two(mut rules: Rules, messages: &[Message]) -> usize {
+    // 0 : 8 11
+    // 8 : 42 | 42 8
+    // 11 : 42 31 | 42 11 31
+    // is equivalent to
+    // 0 : 42... (n times) 42 (m times)... 31 (m times)...
+    // with n, m in [1, 5] is enough
+    let mut r0 = Vec::new();
+    let n = 5;
+    let m = 5;
+    for r11 in 1..=m {
+        for r8 in 1..=n {
+            let mut sr0 = vec![42; r8];
+            sr0.append(&mut vec![42; r11]);
+            sr0.append(&mut vec![31; r11]);
+
+            r0.push(sr0);
+        }
+    }
+    r0.sort_by_key(|v| v.len());
+    r0.reverse(); // rules are processed in order and to be greedy it is sorted by descending length
+    *rules.get_mut(&0).unwrap() = Rule::Composition(r0);
+    rules.remove(&8);
+    rules.remove(&11);
+
+    messages.iter().filter(|m| is_valid(&rules, m)).count()
+}
+
+/// A message of `a` and `b`.
+pub type Message = Vec<u8>;
+
+pub type Messages = Vec<Message>;
+
+/// Every rule by its number.
+pub type Rules = HashMap<u64, Rule>;
+
+/// The rules, then a blank line and one message per line.
+pub fn parse(s: &str) -> Result<(Rules, Messages), ParseError> {
+    let (rules_text, messages) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(s, &s[s.len()..], "a blank line before the messages"))?;
+
+    let rules = parse_lines(rules_text, |l| {
+        let number = terminated(integer(), tag(":"));
+        parse_all(l, pair(number, preceded(spaces1(), rule)))
+    })?
+    .into_iter()
+    .collect::<Rules>();
+
+    if !rules.contains_key(&0) {
+        return Err(ParseError::new(s, rules_text, "a rule number 0"));
+    }
+
+    // Every referenced rule must be defined, or matching a message could not finish.
+    let undefined = rules_text
+        .lines()
+        .filter_map(|l| l.split_once(':'))
+        .flat_map(|(_, rule)| rule.split_whitespace())
+        .find(|n| n.parse().is_ok_and(|n| !rules.contains_key(&n)));
+    if let Some(n) = undefined {
+        return Err(ParseError::new(s, n, "the number of a defined rule"));
+    }
+
+    let messages = messages.lines().map(|m| m.as_bytes().to_vec()).collect();
+
+    Ok((rules, messages))
+}
+
+/// Where matching `rule_number` from `pos` ends, `memo` keeping it for every rule and position
+/// already tried on the same message.
+fn is_valid_inner(
+    memo: &mut Memo<(u64, usize), Option<usize>>,
+    rules: &Rules,
+    rule_number: u64,
+    message: &[u8],
+    pos: usize,
+) -> Option<usize> {
+    memo.get((rule_number, pos), |memo, _| match &rules[&rule_number] {
+        Rule::Match(c) if pos < message.len() && message[pos] == *c => Some(pos + 1),
+        Rule::Match(_) => None,
+        Rule::Composition(sub_rules) => sub_rules.iter().find_map(|rs| {
+            rs.iter().try_fold(pos, |position, &r| {
+                is_valid_inner(memo, rules, r, message, position)
+            })
+        }),
+    })
+}
+
+/// Whether the whole message matches rule 0.
+pub fn is_valid(rules: &Rules, message: &[u8]) -> bool {
+    is_valid_inner(&mut Memo::new(), rules, 0, message, 0)
+        .map(|position| position == message.len())
+        .unwrap_or(false)
+}
+
+/// A rule, like `"a"` or `1 2 | 2 1`.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// Matches a single character.
+    Match(u8),
+    /// Alternatives, each matching a sequence of rules.
+    Composition(Vec<Vec<u64>>),
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(s.trim(), rule).map_err(|e| e.within(s, s.trim()))
+    }
+}
+
+/// A rule after its number, a quoted character or alternatives separated by `|`.
+fn rule(s: &str) -> Parsed<'_, Rule> {
+    let character = and_then(
+        recognize(pair(
+            tag("\""),
+            take_while1(|c: char| !c.is_whitespace(), "`\"`"),
+        )),
+        |rule: &str| match rule.as_bytes() {
+            [b'"', c, b'"'] if c.is_ascii() => Ok(Rule::Match(*c)),
+            _ => Err(Failure::new(rule, "a character rule like `\"a\"`")),
+        },
+    );
+    let sequence = many1(terminated(integer(), optional(spaces1())));
+    let composition = map(separated(sequence, tag("| ")), Rule::Composition);
+
+    or(character, composition)(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_tests() {
+        let input = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+        let (rules, messages) = parse(input).unwrap();
+        assert_eq!(part_one(&rules, &messages), 2);
+    }
+
+    #[test]
+    fn part_two_tests() {
+        let input = r#"42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#;
+        let (rules, messages) = parse(input).unwrap();
+        assert_eq!(part_two(rules, &messages), 12);
+    }
+
+    #[test]
+    fn parse_errors_tests() {
+        let e = parse("0: 1 2\n1: \"a\"\n2: 1 x\n\nab").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 6, "x"));
+
+        let e = parse("0: 1 3\n1: \"a\"\n\nab").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 6, "3"));
+
+        let e = parse("0: 1\n1: \"ab\"\n\nab").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "\"ab\""));
+    }
+}